@@ -5,6 +5,11 @@ pub enum BackendError {
     #[error("Backend not found")]
     NotFound,
 
+    /// The backend binary disappeared after detection, e.g. it was
+    /// uninstalled while the app was running.
+    #[error("Backend is no longer installed")]
+    BackendMissing,
+
     #[error("Command failed: {stderr}")]
     CommandFailed { stderr: String },
 
@@ -20,6 +25,12 @@ pub enum BackendError {
     #[error("Version not found: {0}")]
     VersionNotFound(String),
 
+    /// The backend refused to uninstall because the version is the current
+    /// default (e.g. fnm's default symlink still points at it). Setting a
+    /// different default first unblocks the uninstall.
+    #[error("Cannot uninstall the current default version")]
+    UninstallBlockedByDefault,
+
     #[error("IO error: {0}")]
     IoError(String),
 
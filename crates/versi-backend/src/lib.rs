@@ -4,10 +4,10 @@ mod types;
 
 pub use error::BackendError;
 pub use traits::{
-    BackendDetection, BackendInfo, BackendProvider, BackendUpdate, ManagerCapabilities,
-    ShellInitOptions, VersionManager,
+    BackendDetection, BackendInfo, BackendProvider, BackendUpdate, InstallOptions,
+    ManagerCapabilities, ShellInitOptions, VersionManager,
 };
 pub use types::{
-    InstallPhase, InstallProgress, InstalledVersion, NodeVersion, RemoteVersion, VersionGroup,
-    VersionParseError,
+    ExecOutput, InstallPhase, InstallProgress, InstalledVersion, NodeVersion, RemoteVersion,
+    VersionGroup, VersionParseError,
 };
@@ -3,7 +3,7 @@ use std::path::PathBuf;
 use tokio::sync::mpsc;
 
 use crate::error::BackendError;
-use crate::types::{InstallProgress, InstalledVersion, NodeVersion, RemoteVersion};
+use crate::types::{ExecOutput, InstallProgress, InstalledVersion, NodeVersion, RemoteVersion};
 
 #[derive(Debug, Clone)]
 pub struct BackendDetection {
@@ -44,6 +44,13 @@ pub trait BackendProvider: Send + Sync {
     fn wsl_search_paths(&self) -> Vec<&'static str> {
         vec![]
     }
+
+    /// A short user-facing note when the given backend version is known to
+    /// emit install progress output the app can't parse, so the progress bar
+    /// may look stuck. `None` when progress is expected to display correctly.
+    fn progress_compatibility_note(&self, _backend_version: &str) -> Option<String> {
+        None
+    }
 }
 
 #[derive(Debug, Clone, Default)]
@@ -51,6 +58,7 @@ pub struct ManagerCapabilities {
     pub supports_progress: bool,
     pub supports_lts_filter: bool,
     pub supports_use_version: bool,
+    pub supports_exec: bool,
     pub supports_shell_integration: bool,
     pub supports_auto_switch: bool,
     pub supports_corepack: bool,
@@ -64,6 +72,31 @@ pub struct BackendInfo {
     pub version: Option<String>,
     pub data_dir: Option<PathBuf>,
     pub in_path: bool,
+    /// The Node distribution mirror this backend downloads from, when one is
+    /// configured. `None` means the official nodejs.org mirror.
+    pub node_dist_mirror: Option<String>,
+    /// Architecture override the backend installs for (`arm64`, `x64`, ...),
+    /// when one is configured. `None` means the machine's native arch.
+    pub arch: Option<String>,
+}
+
+impl BackendInfo {
+    /// The local directory behind the configured mirror when it points at the
+    /// filesystem (a `file://` URL or a plain absolute path) — the air-gapped
+    /// setup where tarballs are pre-downloaded. `None` for remote mirrors.
+    pub fn offline_mirror(&self) -> Option<&str> {
+        let mirror = self.node_dist_mirror.as_deref()?;
+        if let Some(path) = mirror.strip_prefix("file://") {
+            return Some(path);
+        }
+        let absolute = mirror.starts_with('/')
+            || mirror.starts_with('\\')
+            || mirror
+                .chars()
+                .next()
+                .is_some_and(|c| c.is_ascii_alphabetic() && mirror[1..].starts_with(":\\"));
+        absolute.then_some(mirror)
+    }
 }
 
 #[derive(Debug, Clone, Default)]
@@ -73,6 +106,22 @@ pub struct ShellInitOptions {
     pub corepack_enabled: bool,
 }
 
+/// Install-time flags, for backends that support them. Callers should gate
+/// each flag on the matching [`ManagerCapabilities`] field.
+#[derive(Debug, Clone, Default)]
+pub struct InstallOptions {
+    pub corepack_enabled: bool,
+    pub resolve_engines: bool,
+    /// `HTTPS_PROXY` URL (possibly with embedded credentials) for the install
+    /// subprocess. When `None` the backend inherits the process environment,
+    /// so an externally set proxy still applies.
+    pub https_proxy: Option<String>,
+    /// Seconds before a hung install is killed and reported as failed, so a
+    /// stalled download can't occupy a queue slot forever. `None` waits
+    /// indefinitely.
+    pub timeout_secs: Option<u64>,
+}
+
 #[async_trait]
 pub trait VersionManager: Send + Sync + VersionManagerClone {
     fn name(&self) -> &'static str;
@@ -81,6 +130,14 @@ pub trait VersionManager: Send + Sync + VersionManagerClone {
 
     fn backend_info(&self) -> &BackendInfo;
 
+    /// Cheap health check that the backend binary is actually runnable (a
+    /// `--version` invocation), so a stale path fails fast with one clear
+    /// error instead of every subsequent command failing. Backends without
+    /// a cheap probe report healthy.
+    async fn probe(&self) -> Result<(), BackendError> {
+        Ok(())
+    }
+
     async fn list_installed(&self) -> Result<Vec<InstalledVersion>, BackendError>;
 
     async fn list_remote(&self) -> Result<Vec<RemoteVersion>, BackendError>;
@@ -89,11 +146,22 @@ pub trait VersionManager: Send + Sync + VersionManagerClone {
 
     async fn default_version(&self) -> Result<Option<NodeVersion>, BackendError>;
 
+    /// Whether `version` is already installed, so callers can skip redundant
+    /// installs without re-scanning. Accepts the loose forms users type
+    /// (`20`, `v20`, `20.11.1`); see [`NodeVersion::satisfies`]. The default
+    /// goes through [`Self::list_installed`]; backends with a cheaper check
+    /// can override.
+    async fn is_installed(&self, version: &str) -> Result<bool, BackendError> {
+        let installed = self.list_installed().await?;
+        Ok(installed.iter().any(|v| v.version.satisfies(version)))
+    }
+
     async fn install(&self, version: &str) -> Result<(), BackendError>;
 
     async fn install_with_progress(
         &self,
         version: &str,
+        options: &InstallOptions,
     ) -> Result<mpsc::UnboundedReceiver<InstallProgress>, BackendError>;
 
     async fn uninstall(&self, version: &str) -> Result<(), BackendError>;
@@ -104,6 +172,49 @@ pub trait VersionManager: Send + Sync + VersionManagerClone {
         Err(BackendError::Unsupported("use_version".to_string()))
     }
 
+    /// Directory where the given installed version lives under the backend's
+    /// data dir, for revealing in a file manager. `None` when the data dir
+    /// is unknown or not reachable from the host file manager (e.g. inside
+    /// a WSL distro).
+    fn version_install_dir(&self, _version: &str) -> Option<PathBuf> {
+        None
+    }
+
+    /// Runs an arbitrary command under the given version without changing
+    /// the shell's active version. A non-zero exit code from the command is
+    /// not an error; it is reported in the returned [`ExecOutput`].
+    async fn exec(&self, _version: &str, _args: &[String]) -> Result<ExecOutput, BackendError> {
+        Err(BackendError::Unsupported("exec".to_string()))
+    }
+
+    /// The npm version bundled with the given installed Node version
+    /// (`"10.2.4"`), read by running `node -p process.versions.npm` under
+    /// it. `Ok(None)` when the backend can't run commands under a version
+    /// or the probe produces nothing usable. Callers should fetch lazily
+    /// and cache: this spawns a Node process per call.
+    async fn bundled_npm(&self, version: &str) -> Result<Option<String>, BackendError> {
+        if !self.capabilities().supports_exec {
+            return Ok(None);
+        }
+        let args: Vec<String> = ["node", "-p", "process.versions.npm"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let output = self.exec(version, &args).await?;
+        let npm = output.stdout.trim();
+        Ok(
+            (output.exit_code == Some(0) && !npm.is_empty() && npm != "undefined")
+                .then(|| npm.to_string()),
+        )
+    }
+
+    /// Aliases currently defined in the backend (`default` plus any custom
+    /// ones), each paired with the version it points at. Backends without
+    /// alias support return an empty list.
+    async fn list_aliases(&self) -> Result<Vec<(String, NodeVersion)>, BackendError> {
+        Ok(Vec::new())
+    }
+
     async fn list_remote_lts(&self) -> Result<Vec<RemoteVersion>, BackendError> {
         let all = self.list_remote().await?;
         Ok(all
@@ -139,3 +250,49 @@ impl<T: VersionManager + Clone + 'static> From<T> for Box<dyn VersionManager> {
         Box::new(manager)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn info(mirror: Option<&str>) -> BackendInfo {
+        BackendInfo {
+            name: "fnm",
+            path: PathBuf::from("fnm"),
+            version: None,
+            data_dir: None,
+            in_path: true,
+            node_dist_mirror: mirror.map(|m| m.to_string()),
+            arch: None,
+        }
+    }
+
+    #[test]
+    fn test_offline_mirror_file_url() {
+        assert_eq!(
+            info(Some("file:///srv/node-dist")).offline_mirror(),
+            Some("/srv/node-dist")
+        );
+    }
+
+    #[test]
+    fn test_offline_mirror_plain_paths() {
+        assert_eq!(
+            info(Some("/srv/node-dist")).offline_mirror(),
+            Some("/srv/node-dist")
+        );
+        assert_eq!(
+            info(Some("D:\\mirrors\\node")).offline_mirror(),
+            Some("D:\\mirrors\\node")
+        );
+    }
+
+    #[test]
+    fn test_offline_mirror_remote_and_unset() {
+        assert_eq!(
+            info(Some("https://npmmirror.com/mirrors/node")).offline_mirror(),
+            None
+        );
+        assert_eq!(info(None).offline_mirror(), None);
+    }
+}
@@ -22,6 +22,28 @@ impl NodeVersion {
     pub fn major_group(&self) -> u32 {
         self.major
     }
+
+    /// Whether this version satisfies a loosely written request: `20` and
+    /// `v20` match any 20.x.y, `20.11` any 20.11.y, and a full triple must
+    /// match exactly. Unparseable requests match nothing.
+    pub fn satisfies(&self, request: &str) -> bool {
+        let request = request.trim();
+        let request = request.strip_prefix('v').unwrap_or(request);
+        if request.is_empty() {
+            return false;
+        }
+
+        let own = [self.major, self.minor, self.patch];
+        let mut parts = request.split('.');
+        for (component, part) in own.iter().zip(parts.by_ref()) {
+            match part.parse::<u32>() {
+                Ok(n) if n == *component => {}
+                _ => return false,
+            }
+        }
+        // Anything beyond X.Y.Z is not a version request.
+        parts.next().is_none()
+    }
 }
 
 impl Ord for NodeVersion {
@@ -60,7 +82,21 @@ impl FromStr for NodeVersion {
     type Err = VersionParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let s = s.trim().strip_prefix('v').unwrap_or(s.trim());
+        // Backend listings decorate versions: `* v20.11.0 default`,
+        // `v20.11.0 (Iron)`. Strip the markers, then parse the first
+        // version-looking token and ignore trailing annotations.
+        let cleaned = s.trim().trim_start_matches('*').trim();
+        let token = cleaned
+            .split_whitespace()
+            .find(|t| {
+                t.trim_start_matches('v')
+                    .chars()
+                    .next()
+                    .is_some_and(|c| c.is_ascii_digit())
+            })
+            .ok_or_else(|| VersionParseError(format!("No version found in: {}", s)))?;
+
+        let s = token.strip_prefix('v').unwrap_or(token);
         let parts: Vec<&str> = s.split('.').collect();
 
         if parts.len() < 3 {
@@ -91,6 +127,11 @@ pub struct InstalledVersion {
     pub lts_codename: Option<String>,
     pub install_date: Option<chrono::DateTime<chrono::Utc>>,
     pub disk_size: Option<u64>,
+    /// CPU architecture the build was installed for (`arm64`, `x64`, ...),
+    /// when the backend can determine it. Disambiguates the same version
+    /// number installed for two architectures.
+    #[serde(default)]
+    pub arch: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -105,6 +146,10 @@ pub struct VersionGroup {
     pub major: u32,
     pub versions: Vec<InstalledVersion>,
     pub is_expanded: bool,
+    /// Display label replacing the `Node {major}.x` header: the LTS codename
+    /// ("Iron"), or "Current" for the merged non-LTS group. `None` when the
+    /// group is plain major-number grouping.
+    pub label: Option<String>,
 }
 
 impl VersionGroup {
@@ -129,10 +174,49 @@ impl VersionGroup {
                     major,
                     versions,
                     is_expanded: true,
+                    label: None,
                 }
             })
             .collect()
     }
+
+    /// Like [`Self::from_versions`], but labels each LTS major with its
+    /// codename ("Iron", "Hydrogen") and merges the majors newer than the
+    /// newest codenamed one — the Current release lines — into a single
+    /// "Current" group keyed by its highest major. Majors without codenames
+    /// otherwise stay grouped by number.
+    pub fn from_versions_by_codename(versions: Vec<InstalledVersion>) -> Vec<Self> {
+        let mut groups = Self::from_versions(versions);
+
+        for group in &mut groups {
+            group.label = group.versions.iter().find_map(|v| v.lts_codename.clone());
+        }
+
+        let Some(newest_lts) = groups
+            .iter()
+            .filter(|g| g.label.is_some())
+            .map(|g| g.major)
+            .max()
+        else {
+            return groups;
+        };
+
+        let (current, mut rest): (Vec<Self>, Vec<Self>) = groups
+            .into_iter()
+            .partition(|g| g.label.is_none() && g.major > newest_lts);
+
+        // Groups arrive in descending major order, so folding keeps the
+        // merged version list descending too.
+        if let Some(mut merged) = current.into_iter().reduce(|mut acc, g| {
+            acc.versions.extend(g.versions);
+            acc
+        }) {
+            merged.label = Some("Current".to_string());
+            rest.insert(0, merged);
+        }
+
+        rest
+    }
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -146,6 +230,14 @@ pub struct InstallProgress {
     pub error: Option<String>,
 }
 
+/// Captured output of a command run under a specific Node version.
+#[derive(Debug, Clone)]
+pub struct ExecOutput {
+    pub stdout: String,
+    pub stderr: String,
+    pub exit_code: Option<i32>,
+}
+
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub enum InstallPhase {
     #[default]
@@ -183,6 +275,30 @@ mod tests {
         assert_eq!(v.major, 20);
     }
 
+    #[test]
+    fn test_parse_version_with_default_marker() {
+        let v: NodeVersion = "* v20.11.0 default".parse().unwrap();
+        assert_eq!(v, NodeVersion::new(20, 11, 0));
+    }
+
+    #[test]
+    fn test_parse_version_with_lts_annotation() {
+        let v: NodeVersion = "v20.11.0 (Iron)".parse().unwrap();
+        assert_eq!(v, NodeVersion::new(20, 11, 0));
+    }
+
+    #[test]
+    fn test_parse_version_with_trailing_asterisk() {
+        let v: NodeVersion = "v20.11.0 *".parse().unwrap();
+        assert_eq!(v, NodeVersion::new(20, 11, 0));
+    }
+
+    #[test]
+    fn test_parse_version_no_version_token() {
+        let result: Result<NodeVersion, _> = "* default".parse();
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_parse_version_invalid_format() {
         let result: Result<NodeVersion, _> = "v20.11".parse();
@@ -229,6 +345,38 @@ mod tests {
         assert_eq!(v1, v2);
     }
 
+    #[test]
+    fn test_satisfies_bare_major() {
+        let v = NodeVersion::new(20, 11, 1);
+        assert!(v.satisfies("20"));
+        assert!(v.satisfies("v20"));
+        assert!(!v.satisfies("18"));
+    }
+
+    #[test]
+    fn test_satisfies_major_minor() {
+        let v = NodeVersion::new(20, 11, 1);
+        assert!(v.satisfies("20.11"));
+        assert!(!v.satisfies("20.10"));
+    }
+
+    #[test]
+    fn test_satisfies_full_triple() {
+        let v = NodeVersion::new(20, 11, 1);
+        assert!(v.satisfies("20.11.1"));
+        assert!(v.satisfies("v20.11.1"));
+        assert!(!v.satisfies("20.11.0"));
+    }
+
+    #[test]
+    fn test_satisfies_rejects_garbage() {
+        let v = NodeVersion::new(20, 11, 1);
+        assert!(!v.satisfies(""));
+        assert!(!v.satisfies("lts"));
+        assert!(!v.satisfies("20."));
+        assert!(!v.satisfies("20.11.1.0"));
+    }
+
     #[test]
     fn test_version_major_group() {
         let v = NodeVersion::new(20, 11, 0);
@@ -244,6 +392,7 @@ mod tests {
                 lts_codename: None,
                 install_date: None,
                 disk_size: None,
+                arch: None,
             },
             InstalledVersion {
                 version: NodeVersion::new(20, 10, 0),
@@ -251,6 +400,7 @@ mod tests {
                 lts_codename: None,
                 install_date: None,
                 disk_size: None,
+                arch: None,
             },
             InstalledVersion {
                 version: NodeVersion::new(18, 19, 0),
@@ -258,6 +408,7 @@ mod tests {
                 lts_codename: None,
                 install_date: None,
                 disk_size: None,
+                arch: None,
             },
         ];
 
@@ -279,6 +430,7 @@ mod tests {
                 lts_codename: None,
                 install_date: None,
                 disk_size: None,
+                arch: None,
             },
             InstalledVersion {
                 version: NodeVersion::new(20, 11, 0),
@@ -286,6 +438,7 @@ mod tests {
                 lts_codename: None,
                 install_date: None,
                 disk_size: None,
+                arch: None,
             },
         ];
 
@@ -310,9 +463,69 @@ mod tests {
             lts_codename: None,
             install_date: None,
             disk_size: None,
+            arch: None,
         }];
 
         let groups = VersionGroup::from_versions(versions);
         assert!(groups[0].is_expanded);
     }
+
+    fn installed(major: u32, minor: u32, patch: u32, codename: Option<&str>) -> InstalledVersion {
+        InstalledVersion {
+            version: NodeVersion::new(major, minor, patch),
+            is_default: false,
+            lts_codename: codename.map(str::to_string),
+            install_date: None,
+            disk_size: None,
+            arch: None,
+        }
+    }
+
+    #[test]
+    fn test_version_group_by_codename_labels_and_merges_current() {
+        let versions = vec![
+            installed(22, 1, 0, None),
+            installed(21, 7, 3, None),
+            installed(20, 11, 0, Some("Iron")),
+            installed(18, 19, 1, Some("Hydrogen")),
+        ];
+
+        let groups = VersionGroup::from_versions_by_codename(versions);
+
+        assert_eq!(groups.len(), 3);
+        assert_eq!(groups[0].label.as_deref(), Some("Current"));
+        assert_eq!(groups[0].major, 22);
+        assert_eq!(groups[0].versions.len(), 2);
+        assert_eq!(groups[0].versions[0].version.major, 22);
+        assert_eq!(groups[0].versions[1].version.major, 21);
+        assert_eq!(groups[1].label.as_deref(), Some("Iron"));
+        assert_eq!(groups[2].label.as_deref(), Some("Hydrogen"));
+    }
+
+    #[test]
+    fn test_version_group_by_codename_without_codenames_stays_by_major() {
+        let versions = vec![installed(21, 7, 3, None), installed(19, 9, 0, None)];
+
+        let groups = VersionGroup::from_versions_by_codename(versions);
+
+        assert_eq!(groups.len(), 2);
+        assert!(groups.iter().all(|g| g.label.is_none()));
+        assert_eq!(groups[0].major, 21);
+        assert_eq!(groups[1].major, 19);
+    }
+
+    #[test]
+    fn test_version_group_by_codename_older_majors_without_codename_stay_separate() {
+        let versions = vec![
+            installed(20, 11, 0, Some("Iron")),
+            installed(19, 9, 0, None),
+        ];
+
+        let groups = VersionGroup::from_versions_by_codename(versions);
+
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].label.as_deref(), Some("Iron"));
+        assert_eq!(groups[1].label, None);
+        assert_eq!(groups[1].major, 19);
+    }
 }
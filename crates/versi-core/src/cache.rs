@@ -0,0 +1,44 @@
+use std::path::Path;
+
+/// Total size in bytes of everything under `path`, recursively. Symlinks are
+/// counted by their own size rather than followed, so a link out of the tree
+/// can't inflate the total. A missing directory is 0 bytes.
+pub fn dir_size(path: &Path) -> u64 {
+    let Ok(entries) = std::fs::read_dir(path) else {
+        return 0;
+    };
+
+    let mut total = 0;
+    for entry in entries.flatten() {
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        if metadata.is_dir() {
+            total += dir_size(&entry.path());
+        } else {
+            total += metadata.len();
+        }
+    }
+    total
+}
+
+/// Deletes everything inside `path`, keeping the directory itself so the
+/// backend can keep writing into it. A missing directory is not an error.
+pub fn clear_dir_contents(path: &Path) -> std::io::Result<()> {
+    let entries = match std::fs::read_dir(path) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(e),
+    };
+
+    for entry in entries {
+        let entry = entry?;
+        let entry_path = entry.path();
+        if entry.file_type()?.is_dir() {
+            std::fs::remove_dir_all(&entry_path)?;
+        } else {
+            std::fs::remove_file(&entry_path)?;
+        }
+    }
+    Ok(())
+}
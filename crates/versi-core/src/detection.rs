@@ -0,0 +1,147 @@
+use std::path::Path;
+
+/// Extracts the version number from `fnm --version` output.
+///
+/// Depending on the release, fnm prints either `fnm 1.38.1` or a bare
+/// `1.38.1`; both native and WSL detection feed their output through here so
+/// the stored version is consistent. Returns `None` for empty output.
+pub fn parse_fnm_version(output: &str) -> Option<String> {
+    let trimmed = output.trim();
+    let version = trimmed.strip_prefix("fnm ").unwrap_or(trimmed).trim();
+    (!version.is_empty()).then(|| version.to_string())
+}
+
+/// Checks that a custom `FNM_DIR` override points at a usable fnm data
+/// directory before the backend is asked to use it, so a typo'd path fails
+/// with one clear message instead of confusing errors from every command.
+///
+/// Accepts a directory that already has the `node-versions` and `aliases`
+/// layout fnm manages, and also a completely empty directory, since fnm
+/// creates the structure on first install. Anything else — a missing path, a
+/// file, or a populated directory without the layout — is rejected with a
+/// user-facing description. See [`create_fnm_dir_structure`] for fixing the
+/// missing-layout case.
+pub fn validate_fnm_dir(path: &Path) -> Result<(), String> {
+    if !path.exists() {
+        return Err(format!("{} does not exist", path.display()));
+    }
+    if !path.is_dir() {
+        return Err(format!("{} is not a directory", path.display()));
+    }
+
+    let mut missing = Vec::new();
+    if !path.join("node-versions").is_dir() {
+        missing.push("node-versions");
+    }
+    if !path.join("aliases").is_dir() {
+        missing.push("aliases");
+    }
+    if missing.is_empty() {
+        return Ok(());
+    }
+
+    let is_empty = std::fs::read_dir(path)
+        .map(|mut entries| entries.next().is_none())
+        .unwrap_or(false);
+    if is_empty {
+        return Ok(());
+    }
+
+    Err(format!(
+        "{} doesn't look like an fnm directory (missing {})",
+        path.display(),
+        missing.join(" and ")
+    ))
+}
+
+/// Creates the `node-versions`/`aliases` layout inside an override
+/// directory, for pointing fnm at a location that holds other files. The
+/// fix-up for [`validate_fnm_dir`] rejecting a populated directory.
+pub fn create_fnm_dir_structure(path: &Path) -> Result<(), String> {
+    for subdir in ["node-versions", "aliases"] {
+        std::fs::create_dir_all(path.join(subdir))
+            .map_err(|e| format!("Cannot create {}: {}", path.join(subdir).display(), e))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A unique directory under the system temp dir, removed on drop.
+    struct TempDir(std::path::PathBuf);
+
+    impl TempDir {
+        fn new(label: &str) -> Self {
+            let path =
+                std::env::temp_dir().join(format!("versi-test-{}-{}", label, std::process::id()));
+            std::fs::create_dir_all(&path).unwrap();
+            Self(path)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn test_validate_fnm_dir_missing_path() {
+        let dir = TempDir::new("missing");
+        let err = validate_fnm_dir(&dir.0.join("nope")).unwrap_err();
+        assert!(err.contains("does not exist"));
+    }
+
+    #[test]
+    fn test_validate_fnm_dir_accepts_empty_and_laid_out() {
+        let dir = TempDir::new("layout");
+        // Empty: fnm creates the structure on first install.
+        assert_eq!(validate_fnm_dir(&dir.0), Ok(()));
+
+        create_fnm_dir_structure(&dir.0).unwrap();
+        assert_eq!(validate_fnm_dir(&dir.0), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_fnm_dir_rejects_populated_without_layout() {
+        let dir = TempDir::new("populated");
+        std::fs::write(dir.0.join("notes.txt"), "hi").unwrap();
+
+        let err = validate_fnm_dir(&dir.0).unwrap_err();
+        assert!(err.contains("node-versions and aliases"));
+
+        // Partial layout names only what's missing.
+        std::fs::create_dir_all(dir.0.join("node-versions")).unwrap();
+        let err = validate_fnm_dir(&dir.0).unwrap_err();
+        assert!(err.contains("missing aliases"));
+    }
+
+    #[test]
+    fn test_parse_fnm_version_prefixed() {
+        assert_eq!(
+            parse_fnm_version("fnm 1.38.1\n"),
+            Some("1.38.1".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_fnm_version_bare() {
+        assert_eq!(parse_fnm_version("1.36.0"), Some("1.36.0".to_string()));
+    }
+
+    #[test]
+    fn test_parse_fnm_version_surrounding_whitespace() {
+        assert_eq!(
+            parse_fnm_version("  fnm 1.35.0  "),
+            Some("1.35.0".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_fnm_version_empty() {
+        assert_eq!(parse_fnm_version(""), None);
+        assert_eq!(parse_fnm_version("   \n"), None);
+    }
+}
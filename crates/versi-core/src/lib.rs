@@ -1,7 +1,22 @@
+mod cache;
 pub mod commands;
+mod detection;
+mod logtail;
+mod project;
+mod range;
 mod schedule;
 mod update;
 
+pub use cache::{clear_dir_contents, dir_size};
 pub use commands::HideWindow;
+pub use detection::{create_fnm_dir_structure, parse_fnm_version, validate_fnm_dir};
+pub use logtail::read_log_tail;
+pub use project::{
+    read_project_pin, resolve_pin, scan_projects, scan_projects_with_paths, write_project_version,
+};
+pub use range::{is_range_query, resolve_range};
 pub use schedule::{ReleaseSchedule, fetch_release_schedule};
-pub use update::{AppUpdate, GitHubRelease, check_for_update, is_newer_version};
+pub use update::{
+    AppUpdate, GitHubAsset, GitHubRelease, SelfUpdateProgress, check_for_update, is_newer_version,
+    self_update, self_update_supported,
+};
@@ -0,0 +1,66 @@
+use std::io::{Read, Seek, SeekFrom};
+use std::path::Path;
+
+/// Upper bound on how much of a log file is read when extracting the tail.
+/// 512 KB comfortably covers 500 lines of log output without pulling a
+/// multi-megabyte file into memory.
+const TAIL_READ_BYTES: u64 = 512 * 1024;
+
+/// Reads the last `max_lines` lines of the file at `path`.
+///
+/// Files larger than [`TAIL_READ_BYTES`] are handled by seeking to the last
+/// window of that size and discarding its (likely partial) first line.
+pub fn read_log_tail(path: &Path, max_lines: usize) -> std::io::Result<String> {
+    let mut file = std::fs::File::open(path)?;
+    let len = file.metadata()?.len();
+    let truncated = len > TAIL_READ_BYTES;
+    if truncated {
+        file.seek(SeekFrom::End(-(TAIL_READ_BYTES as i64)))?;
+    }
+
+    // The window may start mid-way through a UTF-8 sequence, so decode lossily.
+    let mut bytes = Vec::new();
+    file.read_to_end(&mut bytes)?;
+    let content = String::from_utf8_lossy(&bytes);
+
+    Ok(tail_lines(&content, max_lines, truncated))
+}
+
+fn tail_lines(content: &str, max_lines: usize, drop_first: bool) -> String {
+    let mut lines: Vec<&str> = content.lines().collect();
+    if drop_first && !lines.is_empty() {
+        lines.remove(0);
+    }
+    let start = lines.len().saturating_sub(max_lines);
+    lines[start..].join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tail_lines_shorter_than_limit() {
+        assert_eq!(tail_lines("a\nb\nc", 500, false), "a\nb\nc");
+    }
+
+    #[test]
+    fn test_tail_lines_keeps_last_lines() {
+        let content = (1..=10)
+            .map(|i| i.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert_eq!(tail_lines(&content, 3, false), "8\n9\n10");
+    }
+
+    #[test]
+    fn test_tail_lines_drops_partial_first_line() {
+        assert_eq!(tail_lines("rtial\nb\nc", 500, true), "b\nc");
+    }
+
+    #[test]
+    fn test_tail_lines_empty() {
+        assert_eq!(tail_lines("", 500, false), "");
+        assert_eq!(tail_lines("", 500, true), "");
+    }
+}
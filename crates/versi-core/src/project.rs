@@ -0,0 +1,220 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+use versi_backend::RemoteVersion;
+
+/// Version pin files recognized in a project directory.
+const PIN_FILES: [&str; 2] = [".nvmrc", ".node-version"];
+
+/// Scans each directory and its immediate subdirectories (for folders that
+/// hold several checkouts) for `.nvmrc`/`.node-version` files, returning the
+/// unique pinned versions, sorted.
+pub fn scan_projects(dirs: &[PathBuf]) -> Vec<String> {
+    let mut pins = BTreeSet::new();
+
+    for dir in dirs {
+        collect_pins(dir, &mut pins);
+
+        if let Ok(entries) = std::fs::read_dir(dir) {
+            for entry in entries.flatten() {
+                let path = entry.path();
+                if path.is_dir() {
+                    collect_pins(&path, &mut pins);
+                }
+            }
+        }
+    }
+
+    pins.into_iter().collect()
+}
+
+/// Like [`scan_projects`], but keeps which directory pinned each version,
+/// for messages that name the requiring project.
+pub fn scan_projects_with_paths(dirs: &[PathBuf]) -> Vec<(PathBuf, String)> {
+    let mut pins: BTreeMap<PathBuf, String> = BTreeMap::new();
+
+    for dir in dirs {
+        if let Some((_, pin)) = read_project_pin(dir) {
+            pins.entry(dir.clone()).or_insert(pin);
+        }
+
+        if let Ok(entries) = std::fs::read_dir(dir) {
+            for entry in entries.flatten() {
+                let path = entry.path();
+                if path.is_dir()
+                    && let Some((_, pin)) = read_project_pin(&path)
+                {
+                    pins.entry(path).or_insert(pin);
+                }
+            }
+        }
+    }
+
+    pins.into_iter().collect()
+}
+
+fn collect_pins(dir: &Path, pins: &mut BTreeSet<String>) {
+    for name in PIN_FILES {
+        if let Ok(content) = std::fs::read_to_string(dir.join(name))
+            && let Some(pin) = parse_pin_content(&content)
+        {
+            pins.insert(pin);
+        }
+    }
+}
+
+/// Extracts the pinned version from a pin file's content: the first token of
+/// the first non-empty, non-comment line.
+fn parse_pin_content(content: &str) -> Option<String> {
+    content
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty() && !line.starts_with('#'))
+        .and_then(|line| line.split_whitespace().next())
+        .map(str::to_string)
+}
+
+/// Reads the pin file in `dir` itself (not subdirectories), returning its
+/// path and the pinned version request.
+pub fn read_project_pin(dir: &Path) -> Option<(PathBuf, String)> {
+    for name in PIN_FILES {
+        let path = dir.join(name);
+        if let Ok(content) = std::fs::read_to_string(&path)
+            && let Some(pin) = parse_pin_content(&content)
+        {
+            return Some((path, pin));
+        }
+    }
+    None
+}
+
+/// Rewrites a pin file with a concrete version, replacing aliases like
+/// `lts/*` with the version that was actually installed.
+pub fn write_project_version(path: &Path, version: &str) -> std::io::Result<()> {
+    std::fs::write(path, format!("{}\n", version))
+}
+
+/// Resolves a pin to a concrete remote version. Accepts full versions
+/// (`v20.11.0`), bare majors or prefixes (`18`, `18.19`), LTS aliases
+/// (`lts/iron`), and `node` for the latest release. Returns the newest
+/// matching version.
+pub fn resolve_pin<'a>(pin: &str, versions: &'a [RemoteVersion]) -> Option<&'a RemoteVersion> {
+    let pin = pin.trim();
+
+    if pin.eq_ignore_ascii_case("node") {
+        return versions.iter().max_by(|a, b| a.version.cmp(&b.version));
+    }
+
+    if let Some(codename) = pin.strip_prefix("lts/") {
+        return versions
+            .iter()
+            .filter(|v| {
+                v.lts_codename
+                    .as_deref()
+                    .is_some_and(|c| c.eq_ignore_ascii_case(codename))
+            })
+            .max_by(|a, b| a.version.cmp(&b.version));
+    }
+
+    let bare = pin.strip_prefix('v').unwrap_or(pin);
+    let prefix = format!("{}.", bare);
+
+    versions
+        .iter()
+        .filter(|v| {
+            let s = v.version.to_string();
+            let s = s.trim_start_matches('v');
+            s == bare || s.starts_with(&prefix)
+        })
+        .max_by(|a, b| a.version.cmp(&b.version))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use versi_backend::NodeVersion;
+
+    fn remote(major: u32, minor: u32, patch: u32, lts: Option<&str>) -> RemoteVersion {
+        RemoteVersion {
+            version: NodeVersion::new(major, minor, patch),
+            lts_codename: lts.map(str::to_string),
+            is_latest: false,
+        }
+    }
+
+    fn test_versions() -> Vec<RemoteVersion> {
+        vec![
+            remote(18, 19, 1, Some("Hydrogen")),
+            remote(18, 20, 4, Some("Hydrogen")),
+            remote(20, 11, 0, Some("Iron")),
+            remote(22, 0, 0, None),
+        ]
+    }
+
+    #[test]
+    fn test_parse_pin_content_simple() {
+        assert_eq!(parse_pin_content("18.19.1\n"), Some("18.19.1".to_string()));
+    }
+
+    #[test]
+    fn test_parse_pin_content_skips_comments_and_blanks() {
+        assert_eq!(
+            parse_pin_content("# pinned for CI\n\nv20.11.0\n"),
+            Some("v20.11.0".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_pin_content_empty() {
+        assert_eq!(parse_pin_content("\n\n"), None);
+    }
+
+    #[test]
+    fn test_resolve_pin_full_version() {
+        let versions = test_versions();
+        let v = resolve_pin("v18.19.1", &versions).unwrap();
+        assert_eq!(v.version, NodeVersion::new(18, 19, 1));
+    }
+
+    #[test]
+    fn test_resolve_pin_bare_major() {
+        let versions = test_versions();
+        let v = resolve_pin("18", &versions).unwrap();
+        assert_eq!(v.version, NodeVersion::new(18, 20, 4));
+    }
+
+    #[test]
+    fn test_resolve_pin_major_minor() {
+        let versions = test_versions();
+        let v = resolve_pin("18.19", &versions).unwrap();
+        assert_eq!(v.version, NodeVersion::new(18, 19, 1));
+    }
+
+    #[test]
+    fn test_resolve_pin_lts_alias() {
+        let versions = test_versions();
+        let v = resolve_pin("lts/iron", &versions).unwrap();
+        assert_eq!(v.version, NodeVersion::new(20, 11, 0));
+    }
+
+    #[test]
+    fn test_resolve_pin_node_alias() {
+        let versions = test_versions();
+        let v = resolve_pin("node", &versions).unwrap();
+        assert_eq!(v.version, NodeVersion::new(22, 0, 0));
+    }
+
+    #[test]
+    fn test_resolve_pin_no_match() {
+        let versions = test_versions();
+        assert!(resolve_pin("99", &versions).is_none());
+        assert!(resolve_pin("lts/unknown", &versions).is_none());
+    }
+
+    #[test]
+    fn test_resolve_pin_no_false_prefix_match() {
+        // `18.1` must not match 18.19.x.
+        let versions = test_versions();
+        assert!(resolve_pin("18.1", &versions).is_none());
+    }
+}
@@ -0,0 +1,105 @@
+use semver::{Version, VersionReq};
+
+use versi_backend::RemoteVersion;
+
+/// Returns true if the query uses semver range syntax (`^18`, `~20.1`, `>=16 <19`)
+/// rather than a plain version or prefix search.
+pub fn is_range_query(query: &str) -> bool {
+    let query = query.trim();
+    query.starts_with('^')
+        || query.starts_with('~')
+        || query.starts_with('>')
+        || query.starts_with('<')
+        || query.starts_with('=')
+}
+
+/// Resolves a semver range against the remote version list, returning the
+/// newest version that satisfies it.
+pub fn resolve_range<'a>(range: &str, versions: &'a [RemoteVersion]) -> Option<&'a RemoteVersion> {
+    let range = range.trim();
+
+    // Node-style ranges separate comparators with spaces (`>=16 <19`), while
+    // the semver crate expects commas.
+    let normalized = if range.contains(',') {
+        range.to_string()
+    } else {
+        range.split_whitespace().collect::<Vec<_>>().join(", ")
+    };
+
+    let req = VersionReq::parse(&normalized).ok()?;
+
+    versions
+        .iter()
+        .filter(|v| {
+            req.matches(&Version::new(
+                v.version.major as u64,
+                v.version.minor as u64,
+                v.version.patch as u64,
+            ))
+        })
+        .max_by(|a, b| a.version.cmp(&b.version))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use versi_backend::NodeVersion;
+
+    fn remote(major: u32, minor: u32, patch: u32) -> RemoteVersion {
+        RemoteVersion {
+            version: NodeVersion::new(major, minor, patch),
+            lts_codename: None,
+            is_latest: false,
+        }
+    }
+
+    fn test_versions() -> Vec<RemoteVersion> {
+        vec![
+            remote(16, 20, 2),
+            remote(18, 19, 1),
+            remote(18, 20, 4),
+            remote(20, 11, 0),
+            remote(22, 0, 0),
+        ]
+    }
+
+    #[test]
+    fn test_is_range_query_caret() {
+        assert!(is_range_query("^18"));
+        assert!(is_range_query("~20.1"));
+        assert!(is_range_query(">=16 <19"));
+    }
+
+    #[test]
+    fn test_is_range_query_plain_prefix() {
+        assert!(!is_range_query("18"));
+        assert!(!is_range_query("v20.11"));
+        assert!(!is_range_query("lts"));
+    }
+
+    #[test]
+    fn test_resolve_range_caret() {
+        let versions = test_versions();
+        let best = resolve_range("^18.0.0", &versions).unwrap();
+        assert_eq!(best.version, NodeVersion::new(18, 20, 4));
+    }
+
+    #[test]
+    fn test_resolve_range_space_separated() {
+        let versions = test_versions();
+        let best = resolve_range(">=16 <19", &versions).unwrap();
+        assert_eq!(best.version, NodeVersion::new(18, 20, 4));
+    }
+
+    #[test]
+    fn test_resolve_range_no_match() {
+        let versions = test_versions();
+        assert!(resolve_range("^99", &versions).is_none());
+    }
+
+    #[test]
+    fn test_resolve_range_invalid() {
+        let versions = test_versions();
+        assert!(resolve_range("not-a-range", &versions).is_none());
+    }
+}
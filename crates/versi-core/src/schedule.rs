@@ -21,13 +21,32 @@ pub struct ReleaseSchedule {
     pub versions: HashMap<u32, VersionSchedule>,
 }
 
+/// Parses a schedule date, accepting the formats nodejs.org has used over
+/// time: plain `YYYY-MM-DD` dates as well as full timestamps with or without
+/// a timezone offset.
+fn parse_schedule_date(value: &str) -> Option<NaiveDate> {
+    let value = value.trim();
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        return Some(date);
+    }
+    if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(value) {
+        return Some(dt.date_naive());
+    }
+    if let Ok(dt) = chrono::NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S") {
+        return Some(dt.date());
+    }
+    None
+}
+
 impl ReleaseSchedule {
     pub fn is_active(&self, major: u32) -> bool {
         let Some(schedule) = self.versions.get(&major) else {
             return major >= 18;
         };
 
-        let Ok(end_date) = NaiveDate::parse_from_str(&schedule.end, "%Y-%m-%d") else {
+        // An unparseable end date means we can't prove the line is EOL, so
+        // treat it as active rather than hiding updates for it.
+        let Some(end_date) = parse_schedule_date(&schedule.end) else {
             return true;
         };
 
@@ -77,6 +96,12 @@ pub async fn fetch_release_schedule(client: &reqwest::Client) -> Result<ReleaseS
         .await
         .map_err(|e| format!("Failed to parse release schedule: {}", e))?;
 
+    Ok(schedule_from_raw(raw))
+}
+
+/// Converts the raw keyed-by-`vNN` schedule into [`ReleaseSchedule`], logging
+/// entries whose end date can't be parsed in any known format.
+fn schedule_from_raw(raw: HashMap<String, VersionSchedule>) -> ReleaseSchedule {
     let versions: HashMap<u32, VersionSchedule> = raw
         .into_iter()
         .filter_map(|(key, value)| {
@@ -85,7 +110,17 @@ pub async fn fetch_release_schedule(client: &reqwest::Client) -> Result<ReleaseS
         })
         .collect();
 
-    Ok(ReleaseSchedule { versions })
+    for (major, schedule) in &versions {
+        if parse_schedule_date(&schedule.end).is_none() {
+            log::warn!(
+                "Release schedule entry for Node {} has unparseable end date {:?}",
+                major,
+                schedule.end
+            );
+        }
+    }
+
+    ReleaseSchedule { versions }
 }
 
 #[cfg(test)]
@@ -101,7 +136,7 @@ mod tests {
                 start: "2023-04-18".to_string(),
                 lts: Some("2023-10-24".to_string()),
                 maintenance: Some("2024-10-22".to_string()),
-                end: "2026-04-30".to_string(),
+                end: "2030-04-30".to_string(),
                 codename: Some("Iron".to_string()),
             },
         );
@@ -193,6 +228,56 @@ mod tests {
         assert!(!schedule.is_active(16));
     }
 
+    #[test]
+    fn test_parse_schedule_date_formats() {
+        let expected = NaiveDate::from_ymd_opt(2023, 9, 11).unwrap();
+        assert_eq!(parse_schedule_date("2023-09-11"), Some(expected));
+        assert_eq!(parse_schedule_date(" 2023-09-11 "), Some(expected));
+        assert_eq!(parse_schedule_date("2023-09-11T00:00:00Z"), Some(expected));
+        assert_eq!(
+            parse_schedule_date("2023-09-11T12:30:00+02:00"),
+            Some(expected)
+        );
+        assert_eq!(parse_schedule_date("2023-09-11T00:00:00"), Some(expected));
+        assert_eq!(parse_schedule_date("September 2023"), None);
+        assert_eq!(parse_schedule_date(""), None);
+    }
+
+    #[test]
+    fn test_mixed_format_schedule_snapshot() {
+        // Shapes nodejs.org has actually published: plain dates, full
+        // timestamps, and the occasional unparseable value.
+        let raw: HashMap<String, VersionSchedule> = serde_json::from_str(
+            r#"{
+                "v16": {
+                    "start": "2021-04-20",
+                    "lts": "2021-10-26",
+                    "end": "2023-09-11T00:00:00Z",
+                    "codename": "Gallium"
+                },
+                "v18": {
+                    "start": "2022-04-19T00:00:00.000Z",
+                    "lts": "2022-10-25",
+                    "end": "2030-04-30",
+                    "codename": "Hydrogen"
+                },
+                "v20": {
+                    "start": "2023-04-18",
+                    "lts": "2023-10-24",
+                    "end": "unknown",
+                    "codename": "Iron"
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let schedule = schedule_from_raw(raw);
+        assert!(!schedule.is_active(16));
+        assert!(schedule.is_active(18));
+        // Unparseable end dates fall back to active, not EOL.
+        assert!(schedule.is_active(20));
+    }
+
     #[test]
     fn test_active_lts_versions() {
         let schedule = create_test_schedule();
@@ -1,4 +1,7 @@
 use serde::Deserialize;
+use std::path::Path;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::mpsc;
 
 const GITHUB_REPO: &str = "almeidx/versi";
 
@@ -15,6 +18,15 @@ pub struct GitHubRelease {
     pub tag_name: String,
     pub html_url: String,
     pub body: Option<String>,
+    #[serde(default)]
+    pub assets: Vec<GitHubAsset>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GitHubAsset {
+    pub name: String,
+    pub browser_download_url: String,
+    pub size: u64,
 }
 
 pub async fn check_for_update(
@@ -60,6 +72,214 @@ pub async fn check_for_update(
     }
 }
 
+/// Progress of an in-place app update, streamed to the UI while the new
+/// binary downloads and gets swapped in.
+#[derive(Debug, Clone)]
+pub enum SelfUpdateProgress {
+    Downloading {
+        percent: Option<f32>,
+    },
+    Applying,
+    /// The new binary is in place; the caller restarts the process.
+    Complete,
+    Failed(String),
+}
+
+/// Whether this build can update itself in place. The running executable
+/// must be writable, and a binary inside a macOS `.app` bundle can't swap
+/// itself file-by-file — the bundle has to be replaced as a whole, so those
+/// installs fall back to the browser.
+pub fn self_update_supported() -> bool {
+    let Ok(exe) = std::env::current_exe() else {
+        return false;
+    };
+    if cfg!(target_os = "macos")
+        && exe
+            .components()
+            .any(|c| c.as_os_str().to_string_lossy().ends_with(".app"))
+    {
+        return false;
+    }
+    !exe.metadata()
+        .map(|m| m.permissions().readonly())
+        .unwrap_or(true)
+}
+
+/// Downloads the release asset built for this platform and swaps it in for
+/// the running executable, reporting progress on the returned channel. The
+/// caller restarts the process once [`SelfUpdateProgress::Complete`] arrives.
+pub fn self_update(
+    client: reqwest::Client,
+    latest_version: String,
+) -> mpsc::UnboundedReceiver<SelfUpdateProgress> {
+    let (tx, rx) = mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        let result = run_self_update(&client, &latest_version, &tx).await;
+        let _ = tx.send(match result {
+            Ok(()) => SelfUpdateProgress::Complete,
+            Err(e) => SelfUpdateProgress::Failed(e),
+        });
+    });
+    rx
+}
+
+async fn run_self_update(
+    client: &reqwest::Client,
+    latest_version: &str,
+    tx: &mpsc::UnboundedSender<SelfUpdateProgress>,
+) -> Result<(), String> {
+    let url = format!(
+        "https://api.github.com/repos/{}/releases/tags/v{}",
+        GITHUB_REPO, latest_version
+    );
+    let response = client
+        .get(&url)
+        .header("User-Agent", "versi")
+        .send()
+        .await
+        .map_err(|e| format!("Failed to fetch release: {}", e))?;
+    if !response.status().is_success() {
+        return Err(format!(
+            "Failed to fetch release: HTTP {}",
+            response.status()
+        ));
+    }
+    let release: GitHubRelease = response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse release: {}", e))?;
+
+    let asset = matching_asset(&release.assets)
+        .ok_or_else(|| "No release binary for this platform".to_string())?;
+
+    let _ = tx.send(SelfUpdateProgress::Downloading { percent: Some(0.0) });
+
+    let mut response = client
+        .get(&asset.browser_download_url)
+        .header("User-Agent", "versi")
+        .send()
+        .await
+        .map_err(|e| format!("Download failed: {}", e))?;
+    if !response.status().is_success() {
+        return Err(format!("Download failed: HTTP {}", response.status()));
+    }
+
+    let current_exe =
+        std::env::current_exe().map_err(|e| format!("Cannot locate executable: {}", e))?;
+    // Stage next to the target so the final rename stays on one filesystem.
+    let staging = current_exe.with_extension("update");
+
+    let mut file = tokio::fs::File::create(&staging)
+        .await
+        .map_err(|e| format!("Cannot write update: {}", e))?;
+    let mut downloaded: u64 = 0;
+    loop {
+        match response.chunk().await {
+            Ok(Some(chunk)) => {
+                file.write_all(&chunk)
+                    .await
+                    .map_err(|e| format!("Cannot write update: {}", e))?;
+                downloaded += chunk.len() as u64;
+                let _ = tx.send(SelfUpdateProgress::Downloading {
+                    percent: (asset.size > 0)
+                        .then(|| (downloaded as f32 / asset.size as f32) * 100.0),
+                });
+            }
+            Ok(None) => break,
+            Err(e) => {
+                let _ = tokio::fs::remove_file(&staging).await;
+                return Err(format!("Download failed: {}", e));
+            }
+        }
+    }
+    file.flush()
+        .await
+        .map_err(|e| format!("Cannot write update: {}", e))?;
+    drop(file);
+
+    // The published size is the only integrity check available without a
+    // checksums asset; a truncated download must not replace the binary.
+    if downloaded != asset.size {
+        let _ = tokio::fs::remove_file(&staging).await;
+        return Err(format!(
+            "Downloaded {} bytes, expected {}",
+            downloaded, asset.size
+        ));
+    }
+
+    let _ = tx.send(SelfUpdateProgress::Applying);
+    apply_update(&staging, &current_exe)
+}
+
+/// Picks the release asset built for this OS and architecture. Matching is
+/// by file-name token (`versi-linux-x64`, `versi-windows-arm64.exe`, ...);
+/// archives are skipped since they can't be swapped in directly.
+fn matching_asset(assets: &[GitHubAsset]) -> Option<&GitHubAsset> {
+    let os = if cfg!(windows) {
+        "windows"
+    } else if cfg!(target_os = "macos") {
+        "macos"
+    } else {
+        "linux"
+    };
+    let arch = if cfg!(target_arch = "aarch64") {
+        "arm64"
+    } else {
+        "x64"
+    };
+    asset_for_platform(assets, os, arch)
+}
+
+fn asset_for_platform<'a>(
+    assets: &'a [GitHubAsset],
+    os: &str,
+    arch: &str,
+) -> Option<&'a GitHubAsset> {
+    let installable = |a: &&GitHubAsset| {
+        let name = a.name.to_lowercase();
+        !name.ends_with(".tar.gz") && !name.ends_with(".zip") && !name.ends_with(".dmg")
+    };
+    // Exact OS+arch match first, then any asset for the OS (single-arch
+    // releases don't put the architecture in the name).
+    assets
+        .iter()
+        .filter(installable)
+        .find(|a| {
+            let name = a.name.to_lowercase();
+            name.contains(os) && name.contains(arch)
+        })
+        .or_else(|| {
+            assets
+                .iter()
+                .filter(installable)
+                .find(|a| a.name.to_lowercase().contains(os))
+        })
+}
+
+fn apply_update(staging: &Path, current: &Path) -> Result<(), String> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(staging, std::fs::Permissions::from_mode(0o755))
+            .map_err(|e| format!("Cannot mark update executable: {}", e))?;
+        std::fs::rename(staging, current).map_err(|e| format!("Cannot apply update: {}", e))
+    }
+    #[cfg(windows)]
+    {
+        // The running exe can't be overwritten, but it can be renamed away;
+        // the leftover .old file is cleaned up opportunistically on the next
+        // successful update.
+        let old = current.with_extension("old.exe");
+        let _ = std::fs::remove_file(&old);
+        std::fs::rename(current, &old).map_err(|e| format!("Cannot apply update: {}", e))?;
+        if let Err(e) = std::fs::rename(staging, current) {
+            let _ = std::fs::rename(&old, current);
+            return Err(format!("Cannot apply update: {}", e));
+        }
+        Ok(())
+    }
+}
+
 pub fn is_newer_version(latest: &str, current: &str) -> bool {
     let parse_version = |v: &str| -> Option<(u32, u32, u32)> {
         let parts: Vec<&str> = v.split('.').collect();
@@ -90,6 +310,43 @@ pub fn is_newer_version(latest: &str, current: &str) -> bool {
 mod tests {
     use super::*;
 
+    fn asset(name: &str) -> GitHubAsset {
+        GitHubAsset {
+            name: name.to_string(),
+            browser_download_url: format!("https://example.com/{}", name),
+            size: 1,
+        }
+    }
+
+    #[test]
+    fn test_asset_for_platform_exact_match() {
+        let assets = vec![
+            asset("versi-linux-x64"),
+            asset("versi-linux-arm64"),
+            asset("versi-windows-x64.exe"),
+        ];
+        assert_eq!(
+            asset_for_platform(&assets, "linux", "arm64").map(|a| a.name.as_str()),
+            Some("versi-linux-arm64")
+        );
+    }
+
+    #[test]
+    fn test_asset_for_platform_falls_back_to_os_only() {
+        let assets = vec![asset("versi-macos"), asset("versi-windows-x64.exe")];
+        assert_eq!(
+            asset_for_platform(&assets, "macos", "arm64").map(|a| a.name.as_str()),
+            Some("versi-macos")
+        );
+    }
+
+    #[test]
+    fn test_asset_for_platform_skips_archives() {
+        let assets = vec![asset("versi-linux-x64.tar.gz"), asset("versi-macos.dmg")];
+        assert!(asset_for_platform(&assets, "linux", "x64").is_none());
+        assert!(asset_for_platform(&assets, "macos", "arm64").is_none());
+    }
+
     #[test]
     fn test_version_comparison() {
         assert!(is_newer_version("1.0.1", "1.0.0"));
@@ -2,6 +2,8 @@ use async_trait::async_trait;
 use log::{debug, error, info, trace};
 use std::path::PathBuf;
 use std::process::Stdio;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use tokio::io::{AsyncBufReadExt, BufReader};
 use tokio::process::Command;
 use tokio::sync::mpsc;
@@ -9,12 +11,50 @@ use tokio::sync::mpsc;
 use versi_core::HideWindow;
 
 use versi_backend::{
-    BackendError, BackendInfo, InstallPhase, InstallProgress, InstalledVersion,
-    ManagerCapabilities, NodeVersion, RemoteVersion, ShellInitOptions, VersionManager,
+    BackendError, BackendInfo, ExecOutput, InstallOptions, InstallPhase, InstallProgress,
+    InstalledVersion, ManagerCapabilities, NodeVersion, RemoteVersion, ShellInitOptions,
+    VersionManager,
 };
 
 use crate::progress::parse_progress_line;
-use crate::version::{parse_installed_versions, parse_remote_versions};
+use crate::version::{parse_aliases, parse_installed_versions, parse_remote_versions};
+
+/// Log level passed to every fnm invocation. `info` makes install progress
+/// output more consistent across fnm versions than the default, which is
+/// what the progress parser relies on.
+const FNM_LOG_LEVEL: &str = "info";
+
+/// How many extra attempts a failed WSL command gets when the failure
+/// looks like a transient distro-startup error.
+const WSL_RETRY_ATTEMPTS: u32 = 2;
+const WSL_RETRY_DELAY_MS: u64 = 750;
+
+/// Whether stderr from `wsl.exe` indicates a transient startup failure
+/// (the distro is still booting) rather than a real command error.
+fn is_transient_wsl_error(stderr: &str) -> bool {
+    let lower = stderr.to_lowercase();
+    lower.contains("in the process of starting")
+        || lower.contains("is starting")
+        || lower.contains("try again")
+        || lower.contains("wsl_e_busy")
+}
+
+/// Whether stderr from `fnm uninstall` means the version is pinned by the
+/// default alias symlink rather than some other failure. fnm's wording has
+/// varied across releases, so match loosely.
+fn is_default_uninstall_error(stderr: &str) -> bool {
+    let lower = stderr.to_lowercase();
+    (lower.contains("can't uninstall") || lower.contains("cannot uninstall"))
+        && lower.contains("default")
+}
+
+/// The FNM_NODE_DIST_MIRROR value actually handed to fnm. fnm reads local
+/// mirrors as plain directory paths, so a `file://` URL is stripped down to
+/// its path; remote URLs pass through untouched. This applies to installs
+/// and to `fnm list-remote`, so offline mirrors serve the remote list too.
+fn mirror_env_value(mirror: &str) -> &str {
+    mirror.strip_prefix("file://").unwrap_or(mirror)
+}
 
 #[derive(Debug, Clone)]
 pub enum Environment {
@@ -27,6 +67,7 @@ pub struct FnmBackend {
     info: BackendInfo,
     fnm_dir: Option<PathBuf>,
     node_dist_mirror: Option<String>,
+    arch: Option<String>,
     environment: Environment,
 }
 
@@ -39,9 +80,12 @@ impl FnmBackend {
                 version,
                 data_dir: fnm_dir.clone(),
                 in_path: true,
+                node_dist_mirror: None,
+                arch: None,
             },
             fnm_dir,
             node_dist_mirror: None,
+            arch: None,
             environment: Environment::Native,
         }
     }
@@ -53,10 +97,17 @@ impl FnmBackend {
     }
 
     pub fn with_node_dist_mirror(mut self, mirror: String) -> Self {
+        self.info.node_dist_mirror = Some(mirror.clone());
         self.node_dist_mirror = Some(mirror);
         self
     }
 
+    pub fn with_arch(mut self, arch: String) -> Self {
+        self.info.arch = Some(arch.clone());
+        self.arch = Some(arch);
+        self
+    }
+
     pub fn with_wsl(distro: String, fnm_path: String) -> Self {
         Self {
             info: BackendInfo {
@@ -65,13 +116,29 @@ impl FnmBackend {
                 version: None,
                 data_dir: None,
                 in_path: true,
+                node_dist_mirror: None,
+                arch: None,
             },
             fnm_dir: None,
             node_dist_mirror: None,
+            arch: None,
             environment: Environment::Wsl { distro, fnm_path },
         }
     }
 
+    /// Whether the detected fnm version is at least `minimum`. Without a
+    /// parsed version we can't prove a flag is missing, so stay optimistic
+    /// and let fnm reject it.
+    fn version_at_least(&self, minimum: &str) -> bool {
+        match &self.info.version {
+            None => true,
+            Some(v) => {
+                let v = v.strip_prefix('v').unwrap_or(v);
+                !versi_core::is_newer_version(minimum, v)
+            }
+        }
+    }
+
     fn build_command(&self, args: &[&str]) -> Command {
         match &self.environment {
             Environment::Native => {
@@ -82,6 +149,9 @@ impl FnmBackend {
                 );
 
                 let mut cmd = Command::new(&self.info.path);
+                // Before the subcommand, so it never leaks into `exec`'s
+                // forwarded arguments after `--`.
+                cmd.args(["--log-level", FNM_LOG_LEVEL]);
                 cmd.args(args);
 
                 if let Some(dir) = &self.fnm_dir {
@@ -90,10 +160,16 @@ impl FnmBackend {
                 }
 
                 if let Some(mirror) = &self.node_dist_mirror {
+                    let mirror = mirror_env_value(mirror);
                     debug!("Setting FNM_NODE_DIST_MIRROR={}", mirror);
                     cmd.env("FNM_NODE_DIST_MIRROR", mirror);
                 }
 
+                if let Some(arch) = &self.arch {
+                    debug!("Setting FNM_ARCH={}", arch);
+                    cmd.env("FNM_ARCH", arch);
+                }
+
                 cmd.hide_window();
                 cmd
             }
@@ -107,6 +183,7 @@ impl FnmBackend {
 
                 let mut cmd = Command::new("wsl.exe");
                 cmd.args(["-d", distro, "--", fnm_path]);
+                cmd.args(["--log-level", FNM_LOG_LEVEL]);
                 cmd.args(args);
                 cmd.hide_window();
                 cmd
@@ -117,23 +194,77 @@ impl FnmBackend {
     async fn execute(&self, args: &[&str]) -> Result<String, BackendError> {
         info!("Executing fnm command: {}", args.join(" "));
 
-        let output = self.build_command(args).output().await?;
+        let mut attempt = 0;
+        loop {
+            let output = self
+                .build_command(args)
+                .output()
+                .await
+                .map_err(Self::map_spawn_error)?;
 
-        debug!("fnm command exit status: {:?}", output.status);
-        trace!("fnm stdout: {}", String::from_utf8_lossy(&output.stdout));
+            debug!("fnm command exit status: {:?}", output.status);
+            trace!("fnm stdout: {}", String::from_utf8_lossy(&output.stdout));
 
-        if !output.stderr.is_empty() {
-            trace!("fnm stderr: {}", String::from_utf8_lossy(&output.stderr));
-        }
+            if !output.stderr.is_empty() {
+                trace!("fnm stderr: {}", String::from_utf8_lossy(&output.stderr));
+            }
+
+            if output.status.success() {
+                let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+                debug!("fnm command succeeded, output: {} bytes", stdout.len());
+                return Ok(stdout);
+            }
 
-        if output.status.success() {
-            let stdout = String::from_utf8_lossy(&output.stdout).to_string();
-            debug!("fnm command succeeded, output: {} bytes", stdout.len());
-            Ok(stdout)
-        } else {
             let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+
+            // wsl.exe fails transiently right after a distro boots; a short
+            // retry usually succeeds, so don't surface those immediately.
+            if matches!(self.environment, Environment::Wsl { .. })
+                && attempt < WSL_RETRY_ATTEMPTS
+                && is_transient_wsl_error(&stderr)
+            {
+                attempt += 1;
+                debug!(
+                    "Transient WSL error (attempt {}), retrying: {}",
+                    attempt,
+                    stderr.trim()
+                );
+                tokio::time::sleep(std::time::Duration::from_millis(WSL_RETRY_DELAY_MS)).await;
+                continue;
+            }
+
             error!("fnm command failed: args={:?}, stderr='{}'", args, stderr);
-            Err(BackendError::CommandFailed { stderr })
+            return Err(BackendError::CommandFailed { stderr });
+        }
+    }
+
+    /// Asks a version's node binary for its architecture via
+    /// `fnm exec`, which works in both native and WSL environments.
+    /// Returns `None` rather than failing the listing when the probe
+    /// cannot run.
+    async fn probe_arch(&self, version: &str) -> Option<String> {
+        let using = format!("--using={}", version);
+        let output = self
+            .build_command(&["exec", &using, "--", "node", "-p", "process.arch"])
+            .output()
+            .await
+            .ok()?;
+
+        if !output.status.success() {
+            return None;
+        }
+
+        let arch = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        (!arch.is_empty()).then_some(arch)
+    }
+
+    /// Distinguishes "the fnm binary is gone" (e.g. uninstalled while the
+    /// app runs) from other spawn failures.
+    fn map_spawn_error(err: std::io::Error) -> BackendError {
+        if err.kind() == std::io::ErrorKind::NotFound {
+            BackendError::BackendMissing
+        } else {
+            BackendError::from(err)
         }
     }
 }
@@ -149,10 +280,13 @@ impl VersionManager for FnmBackend {
             supports_progress: true,
             supports_lts_filter: true,
             supports_use_version: true,
+            supports_exec: true,
             supports_shell_integration: true,
             supports_auto_switch: true,
-            supports_corepack: true,
-            supports_resolve_engines: true,
+            // `--corepack-enabled` landed in fnm 1.32.0 and
+            // `--resolve-engines` in fnm 1.36.0.
+            supports_corepack: self.version_at_least("1.32.0"),
+            supports_resolve_engines: self.version_at_least("1.36.0"),
         }
     }
 
@@ -160,19 +294,61 @@ impl VersionManager for FnmBackend {
         &self.info
     }
 
+    fn version_install_dir(&self, version: &str) -> Option<PathBuf> {
+        // WSL paths aren't reachable from the Windows file manager.
+        if matches!(self.environment, Environment::Wsl { .. }) {
+            return None;
+        }
+        Some(self.fnm_dir.as_ref()?.join("node-versions").join(version))
+    }
+
+    async fn probe(&self) -> Result<(), BackendError> {
+        self.execute(&["--version"]).await.map(|_| ())
+    }
+
     async fn list_installed(&self) -> Result<Vec<InstalledVersion>, BackendError> {
         let output = self.execute(&["list"]).await?;
-        Ok(parse_installed_versions(&output))
+        let mut versions = parse_installed_versions(&output);
+
+        // fnm's listing carries no architecture, so ask each version's own
+        // node binary. Builds installed for two architectures via FNM_ARCH
+        // are otherwise indistinguishable in the list.
+        let mut probes = tokio::task::JoinSet::new();
+        for (idx, v) in versions.iter().enumerate() {
+            let backend = self.clone();
+            let version = v.version.to_string();
+            probes.spawn(async move { (idx, backend.probe_arch(&version).await) });
+        }
+        while let Some(result) = probes.join_next().await {
+            if let Ok((idx, arch)) = result {
+                versions[idx].arch = arch;
+            }
+        }
+
+        Ok(versions)
+    }
+
+    async fn list_aliases(&self) -> Result<Vec<(String, NodeVersion)>, BackendError> {
+        let output = self.execute(&["list"]).await?;
+        Ok(parse_aliases(&output))
     }
 
     async fn list_remote(&self) -> Result<Vec<RemoteVersion>, BackendError> {
         let output = self.execute(&["list-remote"]).await?;
-        Ok(parse_remote_versions(&output))
+        let (versions, skipped) = parse_remote_versions(&output);
+        if skipped > 0 {
+            debug!("list-remote: skipped {} unparseable lines", skipped);
+        }
+        Ok(versions)
     }
 
     async fn list_remote_lts(&self) -> Result<Vec<RemoteVersion>, BackendError> {
         let output = self.execute(&["list-remote", "--lts"]).await?;
-        Ok(parse_remote_versions(&output))
+        let (versions, skipped) = parse_remote_versions(&output);
+        if skipped > 0 {
+            debug!("list-remote --lts: skipped {} unparseable lines", skipped);
+        }
+        Ok(versions)
     }
 
     async fn current_version(&self) -> Result<Option<NodeVersion>, BackendError> {
@@ -197,6 +373,16 @@ impl VersionManager for FnmBackend {
             .map(|v| v.version))
     }
 
+    async fn is_installed(&self, version: &str) -> Result<bool, BackendError> {
+        // The default goes through list_installed, which probes each
+        // version's node binary for its architecture; a plain `fnm list`
+        // parse is enough for a yes/no answer.
+        let output = self.execute(&["list"]).await?;
+        Ok(parse_installed_versions(&output)
+            .iter()
+            .any(|v| v.version.satisfies(version)))
+    }
+
     async fn install(&self, version: &str) -> Result<(), BackendError> {
         self.execute(&["install", version]).await?;
         Ok(())
@@ -205,6 +391,7 @@ impl VersionManager for FnmBackend {
     async fn install_with_progress(
         &self,
         version: &str,
+        options: &InstallOptions,
     ) -> Result<mpsc::UnboundedReceiver<InstallProgress>, BackendError> {
         info!(
             "Starting install with progress tracking for version: {}",
@@ -213,19 +400,43 @@ impl VersionManager for FnmBackend {
 
         let (tx, rx) = mpsc::unbounded_channel();
 
-        let mut cmd = self.build_command(&["install", version, "--progress", "never"]);
+        let mut args = vec!["install", version, "--progress", "never"];
+        // `--corepack-enabled` was introduced in fnm 1.32.0 and
+        // `--resolve-engines` in fnm 1.36.0; older fnm versions simply
+        // reject the flag, so callers gate these on `capabilities()`.
+        if options.corepack_enabled {
+            args.push("--corepack-enabled");
+        }
+        if options.resolve_engines {
+            args.push("--resolve-engines");
+        }
+
+        let mut cmd = self.build_command(&args);
+        // fnm can't prompt for proxy credentials from inside Versi, so pass
+        // them along in the environment instead.
+        if let Some(proxy) = &options.https_proxy {
+            debug!("Setting HTTPS_PROXY for install");
+            cmd.env("HTTPS_PROXY", proxy);
+        }
         cmd.stdout(Stdio::piped()).stderr(Stdio::piped());
 
         debug!("Spawning fnm install process...");
-        let mut child = cmd.spawn()?;
+        let mut child = cmd.spawn().map_err(Self::map_spawn_error)?;
         debug!("fnm install process spawned successfully");
 
+        // Some fnm builds emit nothing parseable with `--progress never`, so
+        // the overlay would sit on "Preparing..." until completion. Once any
+        // real update (or the final status) is seen this flag flips and the
+        // heuristic below stays quiet.
+        let saw_progress = Arc::new(AtomicBool::new(false));
+
         let stdout = child
             .stdout
             .take()
             .ok_or_else(|| BackendError::IoError("Failed to capture stdout".to_string()))?;
 
         let tx_stdout = tx.clone();
+        let saw_progress_stdout = saw_progress.clone();
         let version_for_stdout = version.to_string();
         tokio::spawn(async move {
             let mut reader = BufReader::new(stdout).lines();
@@ -237,6 +448,7 @@ impl VersionManager for FnmBackend {
                         "Progress update [{}]: phase={:?}, percent={:?}",
                         version_for_stdout, progress.phase, progress.percent
                     );
+                    saw_progress_stdout.store(true, Ordering::Relaxed);
                     let _ = tx_stdout.send(progress);
                 }
             }
@@ -248,6 +460,7 @@ impl VersionManager for FnmBackend {
             .ok_or_else(|| BackendError::IoError("Failed to capture stderr".to_string()))?;
 
         let tx_stderr = tx.clone();
+        let saw_progress_stderr = saw_progress.clone();
         let (stderr_tx, mut stderr_rx) = mpsc::unbounded_channel::<String>();
         let version_for_stderr = version.to_string();
         tokio::spawn(async move {
@@ -261,15 +474,57 @@ impl VersionManager for FnmBackend {
                         "Progress from stderr [{}]: phase={:?}",
                         version_for_stderr, progress.phase
                     );
+                    saw_progress_stderr.store(true, Ordering::Relaxed);
                     let _ = tx_stderr.send(progress);
                 }
             }
         });
 
+        // Heuristic: if the process has been running briefly with no parsed
+        // progress, report an indeterminate download so the UI doesn't look
+        // stuck. Real updates arriving later simply override it.
+        let tx_heuristic = tx.clone();
+        let saw_progress_heuristic = saw_progress.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(std::time::Duration::from_secs(2)).await;
+            if !saw_progress_heuristic.load(Ordering::Relaxed) {
+                let _ = tx_heuristic.send(InstallProgress {
+                    phase: InstallPhase::Downloading,
+                    ..Default::default()
+                });
+            }
+        });
+
         let tx_final = tx;
         let version_for_final = version.to_string();
+        let timeout_secs = options.timeout_secs;
         tokio::spawn(async move {
-            let status = child.wait().await;
+            let status = match timeout_secs {
+                Some(secs) => {
+                    let deadline = std::time::Duration::from_secs(secs);
+                    match tokio::time::timeout(deadline, child.wait()).await {
+                        Ok(status) => status,
+                        Err(_) => {
+                            error!(
+                                "Installation of {} timed out after {}s, killing process",
+                                version_for_final, secs
+                            );
+                            let _ = child.kill().await;
+                            let _ = tx_final.send(InstallProgress {
+                                phase: InstallPhase::Failed,
+                                error: Some(format!(
+                                    "timed out after {} minutes",
+                                    secs.div_ceil(60)
+                                )),
+                                ..Default::default()
+                            });
+                            return;
+                        }
+                    }
+                }
+                None => child.wait().await,
+            };
+            saw_progress.store(true, Ordering::Relaxed);
             debug!(
                 "fnm install process finished [{}]: {:?}",
                 version_for_final, status
@@ -328,8 +583,13 @@ impl VersionManager for FnmBackend {
     }
 
     async fn uninstall(&self, version: &str) -> Result<(), BackendError> {
-        self.execute(&["uninstall", version]).await?;
-        Ok(())
+        match self.execute(&["uninstall", version]).await {
+            Ok(_) => Ok(()),
+            Err(BackendError::CommandFailed { stderr }) if is_default_uninstall_error(&stderr) => {
+                Err(BackendError::UninstallBlockedByDefault)
+            }
+            Err(e) => Err(e),
+        }
     }
 
     async fn set_default(&self, version: &str) -> Result<(), BackendError> {
@@ -342,6 +602,28 @@ impl VersionManager for FnmBackend {
         Ok(())
     }
 
+    async fn exec(&self, version: &str, args: &[String]) -> Result<ExecOutput, BackendError> {
+        info!("fnm: exec under {}: {}", version, args.join(" "));
+
+        let using = format!("--using={}", version);
+        let mut cmd_args: Vec<&str> = vec!["exec", &using, "--"];
+        cmd_args.extend(args.iter().map(String::as_str));
+
+        let output = self
+            .build_command(&cmd_args)
+            .output()
+            .await
+            .map_err(Self::map_spawn_error)?;
+
+        // Unlike `execute`, a non-zero exit here reflects the user's command
+        // rather than an fnm failure, so the output is returned either way.
+        Ok(ExecOutput {
+            stdout: String::from_utf8_lossy(&output.stdout).to_string(),
+            stderr: String::from_utf8_lossy(&output.stderr).to_string(),
+            exit_code: output.status.code(),
+        })
+    }
+
     fn shell_init_command(&self, shell: &str, options: &ShellInitOptions) -> Option<String> {
         let mut flags = Vec::new();
 
@@ -13,6 +13,84 @@ pub struct FnmDetection {
     pub fnm_dir: Option<PathBuf>,
 }
 
+/// fnm-related environment as the fnm CLI itself resolves it, captured from
+/// `fnm env --json`. Seeding the backend from this keeps Versi's view of the
+/// data dir, mirror, and architecture in sync with what the user's shell uses.
+#[derive(Debug, Clone, Default)]
+pub struct FnmEnv {
+    pub dir: Option<PathBuf>,
+    pub node_dist_mirror: Option<String>,
+    pub arch: Option<String>,
+}
+
+pub(crate) async fn probe_fnm_env(fnm_path: &PathBuf) -> Option<FnmEnv> {
+    if let Some(env) = probe_fnm_env_json(fnm_path).await {
+        return Some(env);
+    }
+
+    // Older fnm releases don't support `env --json`; fall back to parsing
+    // the shell exports a plain `fnm env` prints.
+    let output = Command::new(fnm_path)
+        .arg("env")
+        .hide_window()
+        .output()
+        .await
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    Some(parse_env_exports(&String::from_utf8_lossy(&output.stdout)))
+}
+
+async fn probe_fnm_env_json(fnm_path: &PathBuf) -> Option<FnmEnv> {
+    let output = Command::new(fnm_path)
+        .args(["env", "--json"])
+        .hide_window()
+        .output()
+        .await
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).ok()?;
+    let get = |key: &str| json[key].as_str().map(|s| s.to_string());
+
+    Some(FnmEnv {
+        dir: get("FNM_DIR").map(PathBuf::from),
+        node_dist_mirror: get("FNM_NODE_DIST_MIRROR"),
+        arch: get("FNM_ARCH"),
+    })
+}
+
+/// Pulls the FNM_* values out of plain `fnm env` output, which is a list of
+/// shell-specific export statements (`export X="v"`, `set -gx X "v";`,
+/// `$env:X = "v"`), so the exact syntax around the value is tolerated.
+fn parse_env_exports(output: &str) -> FnmEnv {
+    let get = |key: &str| {
+        output.lines().find_map(|line| {
+            let idx = line.find(key)?;
+            let rest = line[idx + key.len()..].trim_start();
+            let rest = rest.strip_prefix('=').unwrap_or(rest).trim_start();
+            let value = rest
+                .trim_end_matches(';')
+                .trim()
+                .trim_matches('"')
+                .trim_matches('\'');
+            (!value.is_empty()).then(|| value.to_string())
+        })
+    };
+
+    FnmEnv {
+        dir: get("FNM_DIR").map(PathBuf::from),
+        node_dist_mirror: get("FNM_NODE_DIST_MIRROR"),
+        arch: get("FNM_ARCH"),
+    }
+}
+
 pub(crate) async fn detect_fnm() -> FnmDetection {
     let fnm_dir = detect_fnm_dir();
 
@@ -129,14 +207,7 @@ async fn get_fnm_version(path: &PathBuf) -> Option<String> {
         return None;
     }
 
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let version = stdout
-        .trim()
-        .strip_prefix("fnm ")
-        .unwrap_or(stdout.trim())
-        .to_string();
-
-    Some(version)
+    versi_core::parse_fnm_version(&String::from_utf8_lossy(&output.stdout))
 }
 
 pub(crate) async fn install_fnm() -> Result<(), crate::FnmError> {
@@ -190,3 +261,39 @@ pub async fn _check_fnm_update(current_version: &str) -> Option<String> {
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_env_exports_bash() {
+        let output = "export PATH=\"/run/fnm:$PATH\"\nexport FNM_DIR=\"/home/u/.local/share/fnm\"\nexport FNM_NODE_DIST_MIRROR=\"https://nodejs.org/dist\"\nexport FNM_ARCH=\"arm64\"";
+        let env = parse_env_exports(output);
+        assert_eq!(env.dir, Some(PathBuf::from("/home/u/.local/share/fnm")));
+        assert_eq!(
+            env.node_dist_mirror.as_deref(),
+            Some("https://nodejs.org/dist")
+        );
+        assert_eq!(env.arch.as_deref(), Some("arm64"));
+    }
+
+    #[test]
+    fn test_parse_env_exports_fish() {
+        let output = "set -gx FNM_DIR \"/home/u/.fnm\";\nset -gx FNM_ARCH \"x64\";";
+        let env = parse_env_exports(output);
+        assert_eq!(env.dir, Some(PathBuf::from("/home/u/.fnm")));
+        assert_eq!(env.arch.as_deref(), Some("x64"));
+        assert!(env.node_dist_mirror.is_none());
+    }
+
+    #[test]
+    fn test_parse_env_exports_powershell() {
+        let output = "$env:FNM_DIR = \"C:\\Users\\u\\AppData\\Roaming\\fnm\"";
+        let env = parse_env_exports(output);
+        assert_eq!(
+            env.dir,
+            Some(PathBuf::from("C:\\Users\\u\\AppData\\Roaming\\fnm"))
+        );
+    }
+}
@@ -8,6 +8,6 @@ mod version;
 
 pub use backend::{Environment, FnmBackend};
 pub use error::FnmError;
-pub use progress::parse_progress_line;
+pub use progress::{has_progress_parsing_issue, parse_progress_line};
 pub use provider::FnmProvider;
 pub use version::{parse_installed_versions, parse_remote_versions};
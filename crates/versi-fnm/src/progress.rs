@@ -1,5 +1,34 @@
 use versi_backend::{InstallPhase, InstallProgress};
 
+type FnmVersion = (u32, u32, u32);
+
+/// Inclusive fnm version ranges whose progress output
+/// [`parse_progress_line`] is known not to understand. 1.36.0 reworked the
+/// download reporting into a format without the `Downloading`/percent lines;
+/// 1.37.1 restored a parseable format.
+const PROGRESS_ISSUE_RANGES: &[(FnmVersion, FnmVersion)] = &[((1, 36, 0), (1, 37, 0))];
+
+/// Whether the given fnm version (a bare `1.36.0`, as reported by
+/// `fnm --version`) is known to emit install progress output this parser
+/// can't follow, leaving the progress bar stuck at an early phase.
+pub fn has_progress_parsing_issue(fnm_version: &str) -> bool {
+    let Some(version) = parse_fnm_version(fnm_version) else {
+        return false;
+    };
+    PROGRESS_ISSUE_RANGES
+        .iter()
+        .any(|(low, high)| *low <= version && version <= *high)
+}
+
+fn parse_fnm_version(s: &str) -> Option<FnmVersion> {
+    let s = s.trim().trim_start_matches("fnm ").trim_start_matches('v');
+    let mut parts = s.split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+    let patch = parts.next().map_or(Some(0), |p| p.parse().ok())?;
+    Some((major, minor, patch))
+}
+
 pub fn parse_progress_line(line: &str) -> Option<InstallProgress> {
     let line = line.trim();
 
@@ -101,3 +130,29 @@ fn parse_byte_size(s: &str) -> Option<u64> {
         .ok()
         .map(|n| (n * multiplier as f64) as u64)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_progress_issue_range_bounds() {
+        assert!(!has_progress_parsing_issue("1.35.1"));
+        assert!(has_progress_parsing_issue("1.36.0"));
+        assert!(has_progress_parsing_issue("1.36.2"));
+        assert!(has_progress_parsing_issue("1.37.0"));
+        assert!(!has_progress_parsing_issue("1.37.1"));
+    }
+
+    #[test]
+    fn test_progress_issue_tolerates_prefixes() {
+        assert!(has_progress_parsing_issue("v1.36.0"));
+        assert!(has_progress_parsing_issue("fnm 1.36.0"));
+    }
+
+    #[test]
+    fn test_progress_issue_unparseable_version_is_fine() {
+        assert!(!has_progress_parsing_issue(""));
+        assert!(!has_progress_parsing_issue("unknown"));
+    }
+}
@@ -5,15 +5,20 @@ use versi_backend::{
 };
 
 use crate::backend::FnmBackend;
-use crate::detection::{detect_fnm, detect_fnm_dir, install_fnm};
+use crate::detection::{FnmEnv, detect_fnm, detect_fnm_dir, install_fnm, probe_fnm_env};
 use crate::update::check_for_fnm_update;
 
 #[derive(Default)]
-pub struct FnmProvider;
+pub struct FnmProvider {
+    /// Environment reported by `fnm env --json`, probed once during
+    /// detection so managers created later match the CLI's own resolution
+    /// of dir, mirror, and architecture.
+    probed_env: std::sync::Mutex<Option<FnmEnv>>,
+}
 
 impl FnmProvider {
     pub fn new() -> Self {
-        Self
+        Self::default()
     }
 }
 
@@ -37,12 +42,26 @@ impl BackendProvider for FnmProvider {
 
     async fn detect(&self) -> BackendDetection {
         let detection = detect_fnm().await;
+
+        let mut data_dir = detection.fnm_dir;
+        if let Some(path) = &detection.path {
+            let env = probe_fnm_env(path).await;
+            if let Some(env) = &env {
+                // The probed FNM_DIR is what the fnm CLI would actually use,
+                // so prefer it over our own directory guessing.
+                if let Some(dir) = &env.dir {
+                    data_dir = Some(dir.clone());
+                }
+            }
+            *self.probed_env.lock().unwrap() = env;
+        }
+
         BackendDetection {
             found: detection.found,
             path: detection.path,
             version: detection.version,
             in_path: detection.in_path,
-            data_dir: detection.fnm_dir,
+            data_dir,
         }
     }
 
@@ -65,13 +84,28 @@ impl BackendProvider for FnmProvider {
             .path
             .clone()
             .unwrap_or_else(|| std::path::PathBuf::from("fnm"));
-        let data_dir = detection.data_dir.clone().or_else(detect_fnm_dir);
+        let probed = self.probed_env.lock().unwrap().clone().unwrap_or_default();
+        let data_dir = detection
+            .data_dir
+            .clone()
+            .or_else(|| probed.dir.clone())
+            .or_else(detect_fnm_dir);
         let backend = FnmBackend::new(path, detection.version.clone(), data_dir.clone());
         let backend = if let Some(dir) = data_dir {
             backend.with_fnm_dir(dir)
         } else {
             backend
         };
+        let backend = if let Some(mirror) = probed.node_dist_mirror {
+            backend.with_node_dist_mirror(mirror)
+        } else {
+            backend
+        };
+        let backend = if let Some(arch) = probed.arch {
+            backend.with_arch(arch)
+        } else {
+            backend
+        };
         Box::new(backend)
     }
 
@@ -92,4 +126,13 @@ impl BackendProvider for FnmProvider {
             "$HOME/.fnm/fnm",
         ]
     }
+
+    fn progress_compatibility_note(&self, backend_version: &str) -> Option<String> {
+        crate::progress::has_progress_parsing_issue(backend_version).then(|| {
+            format!(
+                "Install progress may not display correctly with fnm {}; consider updating",
+                backend_version
+            )
+        })
+    }
 }
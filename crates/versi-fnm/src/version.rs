@@ -1,4 +1,6 @@
-use versi_backend::{InstalledVersion, RemoteVersion};
+use log::debug;
+
+use versi_backend::{InstalledVersion, NodeVersion, RemoteVersion};
 
 pub fn parse_installed_versions(output: &str) -> Vec<InstalledVersion> {
     output
@@ -25,42 +27,76 @@ pub fn parse_installed_versions(output: &str) -> Vec<InstalledVersion> {
                 lts_codename: None,
                 install_date: None,
                 disk_size: None,
+                arch: None,
             })
         })
         .collect()
 }
 
-pub fn parse_remote_versions(output: &str) -> Vec<RemoteVersion> {
-    output
-        .lines()
-        .filter_map(|line| {
-            let line = line.trim();
-            if line.is_empty() {
-                return None;
-            }
-
-            let parts: Vec<&str> = line.splitn(2, ' ').collect();
-            let version_str = parts[0].trim();
-            let version = version_str.parse().ok()?;
+/// Parses alias names out of `fnm list` output, where aliases trail each
+/// version as a comma-separated list (e.g. `* v20.11.0 default, lts-iron`).
+pub fn parse_aliases(output: &str) -> Vec<(String, NodeVersion)> {
+    let mut aliases = Vec::new();
+    for line in output.lines() {
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        let Some(pos) = tokens.iter().position(|t| t.starts_with('v')) else {
+            continue;
+        };
+        let Ok(version) = tokens[pos].parse::<NodeVersion>() else {
+            continue;
+        };
+        for alias in tokens[pos + 1..]
+            .join(" ")
+            .split(',')
+            .map(str::trim)
+            .filter(|a| !a.is_empty())
+        {
+            aliases.push((alias.to_string(), version.clone()));
+        }
+    }
+    aliases
+}
 
-            let lts_codename = if parts.len() > 1 {
-                let rest = parts[1].trim();
-                if rest.starts_with('(') && rest.ends_with(')') {
-                    Some(rest[1..rest.len() - 1].to_string())
-                } else {
-                    None
-                }
+/// Parses `fnm list-remote` output, skipping lines that don't parse as a
+/// version (fnm log lines, truncated output) instead of dropping the whole
+/// list. Also returns how many non-empty lines were skipped, for diagnostics.
+pub fn parse_remote_versions(output: &str) -> (Vec<RemoteVersion>, usize) {
+    let mut versions = Vec::new();
+    let mut skipped = 0;
+
+    for line in output.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let parts: Vec<&str> = line.splitn(2, ' ').collect();
+        let version_str = parts[0].trim();
+        let Ok(version) = version_str.parse() else {
+            debug!("Skipping unparseable list-remote line: {:?}", line);
+            skipped += 1;
+            continue;
+        };
+
+        let lts_codename = if parts.len() > 1 {
+            let rest = parts[1].trim();
+            if rest.starts_with('(') && rest.ends_with(')') {
+                Some(rest[1..rest.len() - 1].to_string())
             } else {
                 None
-            };
+            }
+        } else {
+            None
+        };
+
+        versions.push(RemoteVersion {
+            version,
+            lts_codename,
+            is_latest: false,
+        });
+    }
 
-            Some(RemoteVersion {
-                version,
-                lts_codename,
-                is_latest: false,
-            })
-        })
-        .collect()
+    (versions, skipped)
 }
 
 #[cfg(test)]
@@ -107,11 +143,39 @@ mod tests {
         assert!(versions[0].is_default);
     }
 
+    #[test]
+    fn test_parse_installed_versions_ignores_log_lines() {
+        // `--log-level info` can prepend informational lines to the output.
+        let output = "info: resolved log level\n* v20.11.0 default\nv18.19.1";
+        let versions = parse_installed_versions(output);
+        assert_eq!(versions.len(), 2);
+        assert!(versions[0].is_default);
+    }
+
+    #[test]
+    fn test_parse_aliases_multiple() {
+        let output = "* v20.11.0 default, lts-iron\nv18.19.1 work\nv16.20.2";
+        let aliases = parse_aliases(output);
+        assert_eq!(aliases.len(), 3);
+        assert_eq!(aliases[0].0, "default");
+        assert_eq!(aliases[0].1.major, 20);
+        assert_eq!(aliases[1].0, "lts-iron");
+        assert_eq!(aliases[2].0, "work");
+        assert_eq!(aliases[2].1.major, 18);
+    }
+
+    #[test]
+    fn test_parse_aliases_none() {
+        let output = "v20.11.0\nv18.19.1\nsystem";
+        assert!(parse_aliases(output).is_empty());
+    }
+
     #[test]
     fn test_parse_remote_versions_basic() {
         let output = "v22.0.0\nv21.7.3\nv20.18.0 (Iron)";
-        let versions = parse_remote_versions(output);
+        let (versions, skipped) = parse_remote_versions(output);
         assert_eq!(versions.len(), 3);
+        assert_eq!(skipped, 0);
         assert_eq!(versions[0].version.major, 22);
         assert!(versions[0].lts_codename.is_none());
         assert_eq!(versions[2].lts_codename, Some("Iron".to_string()));
@@ -120,23 +184,43 @@ mod tests {
     #[test]
     fn test_parse_remote_versions_empty() {
         let output = "";
-        let versions = parse_remote_versions(output);
+        let (versions, skipped) = parse_remote_versions(output);
         assert!(versions.is_empty());
+        assert_eq!(skipped, 0);
     }
 
     #[test]
     fn test_parse_remote_versions_lts_codename() {
         let output = "v20.18.0 (Iron)\nv18.20.0 (Hydrogen)";
-        let versions = parse_remote_versions(output);
+        let (versions, _) = parse_remote_versions(output);
         assert_eq!(versions.len(), 2);
         assert_eq!(versions[0].lts_codename, Some("Iron".to_string()));
         assert_eq!(versions[1].lts_codename, Some("Hydrogen".to_string()));
     }
 
+    #[test]
+    fn test_parse_remote_versions_ignores_log_lines() {
+        let output = "info: fetching version list\nv22.0.0\nv20.18.0 (Iron)";
+        let (versions, skipped) = parse_remote_versions(output);
+        assert_eq!(versions.len(), 2);
+        assert_eq!(skipped, 1);
+        assert_eq!(versions[1].lts_codename, Some("Iron".to_string()));
+    }
+
+    #[test]
+    fn test_parse_remote_versions_skips_malformed_lines() {
+        let output = "v22.0.0\ngarbage\nv21.7.3\nerror: request timed out\nv20.18.0 (Iron)";
+        let (versions, skipped) = parse_remote_versions(output);
+        assert_eq!(versions.len(), 3);
+        assert_eq!(skipped, 2);
+        assert_eq!(versions[0].version.major, 22);
+        assert_eq!(versions[2].lts_codename, Some("Iron".to_string()));
+    }
+
     #[test]
     fn test_parse_remote_versions_no_lts() {
         let output = "v23.0.0\nv22.5.0";
-        let versions = parse_remote_versions(output);
+        let (versions, _) = parse_remote_versions(output);
         assert_eq!(versions.len(), 2);
         assert!(versions[0].lts_codename.is_none());
         assert!(versions[1].lts_codename.is_none());
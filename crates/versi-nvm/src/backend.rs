@@ -4,8 +4,8 @@ use std::path::PathBuf;
 use tokio::sync::mpsc;
 
 use versi_backend::{
-    BackendError, BackendInfo, InstallProgress, InstalledVersion, ManagerCapabilities, NodeVersion,
-    RemoteVersion, ShellInitOptions, VersionManager,
+    BackendError, BackendInfo, InstallOptions, InstallProgress, InstalledVersion,
+    ManagerCapabilities, NodeVersion, RemoteVersion, ShellInitOptions, VersionManager,
 };
 
 use crate::client::{NvmClient, NvmEnvironment};
@@ -36,6 +36,8 @@ impl NvmBackend {
                 version,
                 data_dir,
                 in_path: true,
+                node_dist_mirror: None,
+                arch: None,
             },
             client,
         }
@@ -62,6 +64,7 @@ impl VersionManager for NvmBackend {
             supports_progress: false,
             supports_lts_filter: true,
             supports_use_version: true,
+            supports_exec: false,
             supports_shell_integration: supports_shell,
             supports_auto_switch: false,
             supports_corepack: false,
@@ -73,6 +76,29 @@ impl VersionManager for NvmBackend {
         &self.info
     }
 
+    fn version_install_dir(&self, version: &str) -> Option<PathBuf> {
+        match &self.client.environment {
+            NvmEnvironment::Unix { nvm_dir } => {
+                Some(nvm_dir.join("versions").join("node").join(version))
+            }
+            // nvm-windows keeps versions directly under its root directory.
+            NvmEnvironment::Windows { nvm_exe } => nvm_exe.parent().map(|p| p.join(version)),
+            // WSL paths aren't reachable from the Windows file manager.
+            NvmEnvironment::Wsl { .. } => None,
+        }
+    }
+
+    async fn probe(&self) -> Result<(), BackendError> {
+        debug!("nvm: probing binary");
+        self.client
+            .version()
+            .await
+            .map(|_| ())
+            .map_err(|e| BackendError::CommandFailed {
+                stderr: e.to_string(),
+            })
+    }
+
     async fn list_installed(&self) -> Result<Vec<InstalledVersion>, BackendError> {
         debug!("nvm: listing installed versions");
         self.client
@@ -136,6 +162,7 @@ impl VersionManager for NvmBackend {
     async fn install_with_progress(
         &self,
         version: &str,
+        _options: &InstallOptions,
     ) -> Result<mpsc::UnboundedReceiver<InstallProgress>, BackendError> {
         info!("nvm: installing version {} with progress", version);
         self.client
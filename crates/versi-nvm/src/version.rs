@@ -62,6 +62,7 @@ pub fn parse_unix_installed(output: &str) -> Vec<InstalledVersion> {
                 lts_codename: None,
                 install_date: None,
                 disk_size: None,
+                arch: None,
             });
         }
     }
@@ -99,6 +100,7 @@ pub fn parse_windows_installed(output: &str) -> Vec<InstalledVersion> {
                 lts_codename: None,
                 install_date: None,
                 disk_size: None,
+                arch: None,
             });
         }
     }
@@ -29,6 +29,17 @@ impl EnvironmentId {
             EnvironmentId::Wsl { distro, .. } => format!("WSL: {}", distro),
         }
     }
+
+    /// Stable string key for persisting per-environment settings.
+    ///
+    /// Unlike the full id, this ignores the backend path so a relabelled
+    /// WSL distro keeps its label when the backend moves.
+    pub fn settings_key(&self) -> String {
+        match self {
+            EnvironmentId::Native => "native".to_string(),
+            EnvironmentId::Wsl { distro, .. } => format!("wsl:{}", distro),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -232,7 +232,7 @@ fn decode_wsl_output(bytes: &[u8]) -> String {
 }
 
 fn parse_wsl_list(output: &str, running_distros: &[String]) -> Vec<WslDistro> {
-    output
+    let parsed: Vec<WslDistro> = output
         .lines()
         .skip(1)
         .filter_map(|line| {
@@ -269,7 +269,23 @@ fn parse_wsl_list(output: &str, running_distros: &[String]) -> Vec<WslDistro> {
                 None
             }
         })
-        .collect()
+        .collect();
+
+    // Some setups list the same distro twice (once with the default marker,
+    // once plain), which would surface as duplicate environment tabs. Keep
+    // one entry per name, folding in the default/running flags so the
+    // preferred instance wins.
+    let mut distros: Vec<WslDistro> = Vec::new();
+    for distro in parsed {
+        match distros.iter_mut().find(|d| d.name == distro.name) {
+            Some(existing) => {
+                existing.is_default |= distro.is_default;
+                existing.is_running |= distro.is_running;
+            }
+            None => distros.push(distro),
+        }
+    }
+    distros
 }
 
 #[cfg(test)]
@@ -377,6 +393,29 @@ mod tests {
         assert_eq!(distros[0].version, 2);
     }
 
+    #[test]
+    fn test_parse_wsl_list_dedupes_duplicate_names() {
+        let output = "  NAME      STATE           VERSION\n* Ubuntu    Running         2\n  Ubuntu    Running         2\n  Debian    Stopped         2";
+        let running = vec!["Ubuntu".to_string()];
+        let distros = parse_wsl_list(output, &running);
+
+        assert_eq!(distros.len(), 2);
+        assert_eq!(distros[0].name, "Ubuntu");
+        assert!(distros[0].is_default);
+        assert!(distros[0].is_running);
+        assert_eq!(distros[1].name, "Debian");
+    }
+
+    #[test]
+    fn test_parse_wsl_list_dedupe_keeps_default_flag_from_later_entry() {
+        let output = "  NAME      STATE           VERSION\n  Ubuntu    Running         2\n* Ubuntu    Running         2";
+        let running: Vec<String> = vec![];
+        let distros = parse_wsl_list(output, &running);
+
+        assert_eq!(distros.len(), 1);
+        assert!(distros[0].is_default);
+    }
+
     #[test]
     fn test_wsl_distro_backend_path_default() {
         let output = "  NAME      STATE           VERSION\nUbuntu    Running         2";
@@ -7,6 +7,7 @@ pub enum ShellType {
     Bash,
     Zsh,
     Fish,
+    Nushell,
     PowerShell,
     Cmd,
 }
@@ -17,6 +18,7 @@ impl ShellType {
             ShellType::Bash => "Bash",
             ShellType::Zsh => "Zsh",
             ShellType::Fish => "Fish",
+            ShellType::Nushell => "Nushell",
             ShellType::PowerShell => "PowerShell",
             ShellType::Cmd => "Command Prompt",
         }
@@ -27,6 +29,7 @@ impl ShellType {
             ShellType::Bash => "bash",
             ShellType::Zsh => "zsh",
             ShellType::Fish => "fish",
+            ShellType::Nushell => "nushell",
             ShellType::PowerShell => "powershell",
             ShellType::Cmd => "cmd",
         }
@@ -43,6 +46,7 @@ impl ShellType {
             ],
             ShellType::Zsh => vec![home.join(".zshrc"), home.join(".zprofile")],
             ShellType::Fish => vec![home.join(".config/fish/config.fish")],
+            ShellType::Nushell => vec![home.join(".config/nushell/config.nu")],
             ShellType::PowerShell => {
                 #[cfg(target_os = "windows")]
                 {
@@ -65,6 +69,20 @@ impl ShellType {
     }
 }
 
+/// The user's login shell from `$SHELL`, when it maps to a supported shell.
+/// Always `None` on Windows, which has no login-shell concept.
+pub fn login_shell() -> Option<ShellType> {
+    #[cfg(unix)]
+    {
+        let path = PathBuf::from(std::env::var("SHELL").ok()?);
+        shell_type_from_path(&path)
+    }
+    #[cfg(not(unix))]
+    {
+        None
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ShellInfo {
     pub shell_type: ShellType,
@@ -82,34 +100,17 @@ pub fn detect_native_shells() -> Vec<ShellInfo> {
 
     #[cfg(unix)]
     {
-        if let Ok(path) = which("bash") {
-            let config_file = find_existing_config(&ShellType::Bash);
-            shells.push(ShellInfo {
-                shell_type: ShellType::Bash,
-                path: Some(path),
-                config_file,
-                is_configured: false,
-            });
-        }
-
-        if let Ok(path) = which("zsh") {
-            let config_file = find_existing_config(&ShellType::Zsh);
-            shells.push(ShellInfo {
-                shell_type: ShellType::Zsh,
-                path: Some(path),
-                config_file,
-                is_configured: false,
-            });
-        }
-
-        if let Ok(path) = which("fish") {
-            let config_file = find_existing_config(&ShellType::Fish);
-            shells.push(ShellInfo {
-                shell_type: ShellType::Fish,
-                path: Some(path),
-                config_file,
-                is_configured: false,
-            });
+        let mut seen = std::collections::HashSet::new();
+        for (shell_type, path) in unix_shell_candidates() {
+            if seen.insert(shell_type.clone()) {
+                let config_file = find_existing_config(&shell_type);
+                shells.push(ShellInfo {
+                    shell_type,
+                    path: Some(path),
+                    config_file,
+                    is_configured: false,
+                });
+            }
         }
     }
 
@@ -151,6 +152,61 @@ pub fn detect_native_shells() -> Vec<ShellInfo> {
     shells
 }
 
+/// Collects unix shell candidates from `$SHELL`, `/etc/shells`, and PATH
+/// lookups of known shell binaries. A PATH lookup alone misses shells that
+/// are installed but not the login shell, and `$SHELL` alone misses
+/// everything else. Candidates are ordered so the login shell comes first;
+/// the caller deduplicates by shell type.
+#[cfg(unix)]
+fn unix_shell_candidates() -> Vec<(ShellType, PathBuf)> {
+    let mut candidates = Vec::new();
+
+    if let Ok(login_shell) = std::env::var("SHELL") {
+        let path = PathBuf::from(&login_shell);
+        if let Some(shell_type) = shell_type_from_path(&path)
+            && path.exists()
+        {
+            candidates.push((shell_type, path));
+        }
+    }
+
+    if let Ok(contents) = std::fs::read_to_string("/etc/shells") {
+        for line in contents.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let path = PathBuf::from(line);
+            if let Some(shell_type) = shell_type_from_path(&path)
+                && path.exists()
+            {
+                candidates.push((shell_type, path));
+            }
+        }
+    }
+
+    for binary in ["bash", "zsh", "fish", "nu"] {
+        if let Ok(path) = which(binary)
+            && let Some(shell_type) = shell_type_from_path(&path)
+        {
+            candidates.push((shell_type, path));
+        }
+    }
+
+    candidates
+}
+
+#[cfg(unix)]
+fn shell_type_from_path(path: &std::path::Path) -> Option<ShellType> {
+    match path.file_name()?.to_str()? {
+        "bash" => Some(ShellType::Bash),
+        "zsh" => Some(ShellType::Zsh),
+        "fish" => Some(ShellType::Fish),
+        "nu" | "nushell" => Some(ShellType::Nushell),
+        _ => None,
+    }
+}
+
 #[cfg(target_os = "windows")]
 pub fn detect_wsl_shells(distro: &str) -> Vec<ShellInfo> {
     use log::{debug, warn};
@@ -237,6 +293,7 @@ mod tests {
         assert_eq!(ShellType::Bash.name(), "Bash");
         assert_eq!(ShellType::Zsh.name(), "Zsh");
         assert_eq!(ShellType::Fish.name(), "Fish");
+        assert_eq!(ShellType::Nushell.name(), "Nushell");
         assert_eq!(ShellType::PowerShell.name(), "PowerShell");
         assert_eq!(ShellType::Cmd.name(), "Command Prompt");
     }
@@ -246,6 +303,7 @@ mod tests {
         assert_eq!(ShellType::Bash.shell_arg(), "bash");
         assert_eq!(ShellType::Zsh.shell_arg(), "zsh");
         assert_eq!(ShellType::Fish.shell_arg(), "fish");
+        assert_eq!(ShellType::Nushell.shell_arg(), "nushell");
         assert_eq!(ShellType::PowerShell.shell_arg(), "powershell");
         assert_eq!(ShellType::Cmd.shell_arg(), "cmd");
     }
@@ -271,6 +329,28 @@ mod tests {
         assert!(files.iter().any(|p| p.to_string_lossy().contains("fish")));
     }
 
+    #[test]
+    fn test_config_files_nushell() {
+        let files = ShellType::Nushell.config_files();
+        assert!(!files.is_empty());
+        assert!(files.iter().any(|p| p.ends_with("config.nu")));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_shell_type_from_path() {
+        use std::path::Path;
+        assert_eq!(
+            shell_type_from_path(Path::new("/bin/bash")),
+            Some(ShellType::Bash)
+        );
+        assert_eq!(
+            shell_type_from_path(Path::new("/usr/bin/nu")),
+            Some(ShellType::Nushell)
+        );
+        assert_eq!(shell_type_from_path(Path::new("/bin/sh")), None);
+    }
+
     #[test]
     fn test_config_files_cmd() {
         let files = ShellType::Cmd.config_files();
@@ -5,7 +5,9 @@ mod verify;
 pub mod shells;
 
 pub use config::{ShellConfig, ShellConfigEdit};
-pub use detect::{ShellInfo, ShellType, detect_native_shells, detect_shells, detect_wsl_shells};
+pub use detect::{
+    ShellInfo, ShellType, detect_native_shells, detect_shells, detect_wsl_shells, login_shell,
+};
 pub use verify::{
     VerificationResult, get_or_create_config_path, verify_shell_config, verify_wsl_shell_config,
 };
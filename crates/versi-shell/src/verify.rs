@@ -82,6 +82,13 @@ async fn functional_test(shell_type: &ShellType, backend_binary: &str) -> bool {
             .await
             .map(|o| o.status.success())
             .unwrap_or(false),
+        ShellType::Nushell => Command::new("nu")
+            .args(["-c", &version_cmd])
+            .hide_window()
+            .output()
+            .await
+            .map(|o| o.status.success())
+            .unwrap_or(false),
         ShellType::PowerShell => {
             let shell = if which::which("pwsh").is_ok() {
                 "pwsh"
@@ -15,6 +15,8 @@ impl Versi {
         &mut self,
         env_id: EnvironmentId,
         versions: Vec<versi_backend::InstalledVersion>,
+        multishell: Option<versi_backend::NodeVersion>,
+        aliases: Vec<(String, versi_backend::NodeVersion)>,
     ) -> Task<Message> {
         info!(
             "Environment loaded: {:?} with {} versions",
@@ -28,24 +30,134 @@ impl Versi {
             );
         }
 
-        if let AppState::Main(state) = &mut self.state
-            && let Some(env) = state.environments.iter_mut().find(|e| e.id == env_id)
-        {
-            env.update_versions(versions);
+        self.active_env_loads.remove(&env_id);
+
+        let group_by_codename = self.settings.group_by_codename;
+        let expand_default_group = self.settings.auto_expand_default_group;
+        if let AppState::Main(state) = &mut self.state {
+            let default_broken = state
+                .environments
+                .iter_mut()
+                .find(|e| e.id == env_id)
+                .is_some_and(|env| {
+                    env.multishell_version = multishell;
+                    env.aliases = aliases;
+                    env.update_versions(versions, group_by_codename, expand_default_group)
+                });
+            if default_broken {
+                let toast_id = state.next_toast_id();
+                state.add_toast(crate::state::Toast::error(
+                    toast_id,
+                    "Default points to a missing version — please set a new default".to_string(),
+                ));
+            }
+        }
+        // A query carried over from the previous session may no longer match
+        // anything now that versions are known; drop it instead of showing a
+        // confusingly empty list on launch.
+        if self.search_restored {
+            self.search_restored = false;
+            if let AppState::Main(state) = &mut self.state
+                && !state.search_query.is_empty()
+            {
+                let query = state.search_query.clone();
+                let query_lower = query.to_lowercase();
+                let matches_version = |version: &str, lts: Option<&String>| {
+                    version.contains(&query)
+                        || lts.is_some_and(|c| c.to_lowercase().contains(&query_lower))
+                };
+                let any_match =
+                    query_lower == "lts"
+                        || state.range_match.is_some()
+                        || state.environments.iter().any(|env| {
+                            env.installed_versions.iter().any(|v| {
+                                matches_version(&v.version.to_string(), v.lts_codename.as_ref())
+                            })
+                        })
+                        || state.available_versions.versions.iter().any(|v| {
+                            matches_version(&v.version.to_string(), v.lts_codename.as_ref())
+                        });
+                if !any_match {
+                    state.search_query.clear();
+                    state.range_match = None;
+                }
+            }
         }
+
+        self.apply_group_sort_defaults();
         self.update_tray_menu();
 
+        // A floating major pin follows the newest installed release of its
+        // major. When a refresh shows the default lagging behind — typically
+        // right after a newer release of the major was installed — re-run the
+        // bare-major set_default so the backend re-resolves it; the refresh
+        // that follows sees the default caught up and the cycle stops.
+        let repin = if let AppState::Main(state) = &self.state {
+            let env = state.active_environment();
+            self.settings
+                .pinned_default_majors
+                .get(&env.id.settings_key())
+                .copied()
+                .filter(|_| env.id == env_id)
+                .filter(|major| {
+                    env.installed_versions
+                        .iter()
+                        .map(|v| &v.version)
+                        .filter(|v| v.major == *major)
+                        .max()
+                        .is_some_and(|newest| env.default_version.as_ref() != Some(newest))
+                })
+                .filter(|major| {
+                    let request = major.to_string();
+                    !state.operation_queue.is_current_version(&request)
+                        && !state.operation_queue.has_pending_for_version(&request)
+                })
+        } else {
+            None
+        };
+        let repin_task = match repin {
+            Some(major) => self.handle_set_default(major.to_string()),
+            None => Task::none(),
+        };
+
+        let next_loads = self.start_pending_environment_loads();
+
         if self.pending_minimize
             && let Some(id) = self.window_id
         {
             self.pending_minimize = false;
             return Task::batch([
+                next_loads,
+                repin_task,
                 Task::done(Message::HideDockIcon),
                 iced::window::set_mode(id, iced::window::Mode::Hidden),
             ]);
         }
 
-        Task::none()
+        Task::batch([next_loads, repin_task])
+    }
+
+    /// The backend binary for an environment failed its `--version` probe
+    /// (e.g. a stale path after the tool was uninstalled). Marks the
+    /// environment unavailable with the probe error instead of letting
+    /// every subsequent command fail.
+    pub(super) fn handle_environment_probe_failed(
+        &mut self,
+        env_id: EnvironmentId,
+        reason: String,
+    ) -> Task<Message> {
+        info!("Backend probe failed for {:?}: {}", env_id, reason);
+        self.active_env_loads.remove(&env_id);
+
+        if let AppState::Main(state) = &mut self.state
+            && let Some(env) = state.environments.iter_mut().find(|e| e.id == env_id)
+        {
+            env.loading = false;
+            env.available = false;
+            env.error = Some(format!("Backend is not runnable: {}", reason));
+        }
+
+        self.start_pending_environment_loads()
     }
 
     pub(super) fn handle_environment_selected(&mut self, idx: usize) -> Task<Message> {
@@ -59,6 +171,7 @@ impl Versi {
             }
 
             info!("Switching to environment {}", idx);
+            state.renaming_environment = None;
             state.active_environment_idx = idx;
 
             let env = &state.environments[idx];
@@ -88,6 +201,10 @@ impl Versi {
 
             let load_task = if needs_load {
                 info!("Loading versions for environment: {:?}", env_id);
+                // User-initiated loads jump the staged queue.
+                self.pending_env_loads.retain(|id| id != &env_id);
+                self.active_env_loads.insert(env_id.clone());
+
                 let env = state.active_environment_mut();
                 env.loading = true;
 
@@ -95,16 +212,29 @@ impl Versi {
 
                 Task::perform(
                     async move {
+                        if let Err(e) = backend.probe().await {
+                            return Message::EnvironmentProbeFailed {
+                                env_id,
+                                reason: e.to_string(),
+                            };
+                        }
                         debug!("Fetching installed versions for {:?}...", env_id);
                         let versions = backend.list_installed().await.unwrap_or_default();
+                        let multishell = backend.current_version().await.ok().flatten();
+                        let aliases = backend.list_aliases().await.unwrap_or_default();
                         debug!(
                             "Environment {:?} loaded: {} versions",
                             env_id,
                             versions.len(),
                         );
-                        (env_id, versions)
+                        Message::EnvironmentLoaded {
+                            env_id,
+                            versions,
+                            multishell,
+                            aliases,
+                        }
                     },
-                    |(env_id, versions)| Message::EnvironmentLoaded { env_id, versions },
+                    |msg| msg,
                 )
             } else {
                 Task::none()
@@ -122,21 +252,166 @@ impl Versi {
             env.loading = true;
             env.error = None;
             let env_id = env.id.clone();
+            self.active_env_loads.insert(env_id.clone());
 
             state.refresh_rotation = std::f32::consts::TAU / 40.0;
             let backend = state.backend.clone();
 
             return Task::perform(
                 async move {
+                    if let Err(e) = backend.probe().await {
+                        return Message::EnvironmentProbeFailed {
+                            env_id,
+                            reason: e.to_string(),
+                        };
+                    }
+                    let versions = backend.list_installed().await.unwrap_or_default();
+                    let multishell = backend.current_version().await.ok().flatten();
+                    let aliases = backend.list_aliases().await.unwrap_or_default();
+                    Message::EnvironmentLoaded {
+                        env_id,
+                        versions,
+                        multishell,
+                        aliases,
+                    }
+                },
+                |msg| msg,
+            );
+        }
+        Task::none()
+    }
+
+    /// Refreshes the environment an exclusive operation ran against, which
+    /// may no longer be the active one if the user switched tabs while it
+    /// was in flight. Matches [`Self::handle_refresh_environment`] for the
+    /// active environment (keeping its spinner), and otherwise reloads the
+    /// originating environment with a backend built for it.
+    pub(super) fn refresh_environment_by_id(&mut self, env_id: EnvironmentId) -> Task<Message> {
+        let is_active = matches!(
+            &self.state,
+            AppState::Main(state) if state.active_environment().id == env_id
+        );
+        if is_active {
+            return self.handle_refresh_environment();
+        }
+
+        if let AppState::Main(state) = &mut self.state {
+            let Some(env) = state.environments.iter_mut().find(|e| e.id == env_id) else {
+                return Task::none();
+            };
+            env.loading = true;
+            env.error = None;
+            let env_provider = self
+                .providers
+                .get(env.backend_name)
+                .cloned()
+                .unwrap_or_else(|| self.provider.clone());
+            self.active_env_loads.insert(env_id.clone());
+
+            let backend = create_backend_for_environment(
+                &env_id,
+                &self.backend_path,
+                &self.backend_dir,
+                &env_provider,
+            );
+
+            return Task::perform(
+                async move {
+                    if let Err(e) = backend.probe().await {
+                        return Message::EnvironmentProbeFailed {
+                            env_id,
+                            reason: e.to_string(),
+                        };
+                    }
                     let versions = backend.list_installed().await.unwrap_or_default();
-                    (env_id, versions)
+                    let multishell = backend.current_version().await.ok().flatten();
+                    let aliases = backend.list_aliases().await.unwrap_or_default();
+                    Message::EnvironmentLoaded {
+                        env_id,
+                        versions,
+                        multishell,
+                        aliases,
+                    }
                 },
-                |(env_id, versions)| Message::EnvironmentLoaded { env_id, versions },
+                |msg| msg,
             );
         }
         Task::none()
     }
 
+    /// Rebuilds every loaded environment's version groups from its installed
+    /// list, so switching the grouping mode takes effect without a refresh.
+    pub(super) fn regroup_environments(&mut self) {
+        let group_by_codename = self.settings.group_by_codename;
+        if let AppState::Main(state) = &mut self.state {
+            for env in &mut state.environments {
+                let versions = env.installed_versions.clone();
+                env.version_groups = if group_by_codename {
+                    versi_backend::VersionGroup::from_versions_by_codename(versions)
+                } else {
+                    versi_backend::VersionGroup::from_versions(versions)
+                };
+            }
+        }
+        self.apply_group_sort_defaults();
+    }
+
+    /// Collapses EOL version groups when the "LTS first" sort is active, so
+    /// the end-of-life majors pushed to the bottom start out folded. With
+    /// auto-expand enabled the group holding the default is exempt — the
+    /// default should never be hidden by the fold.
+    pub(super) fn apply_group_sort_defaults(&mut self) {
+        if self.settings.group_sort != crate::settings::GroupSort::LtsFirst {
+            return;
+        }
+        let keep_default_open = self.settings.auto_expand_default_group;
+        if let AppState::Main(state) = &mut self.state {
+            let Some(schedule) = &state.available_versions.schedule else {
+                return;
+            };
+            for env in &mut state.environments {
+                let default = env.default_version.clone();
+                for group in &mut env.version_groups {
+                    let holds_default = default
+                        .as_ref()
+                        .is_some_and(|d| group.versions.iter().any(|v| &v.version == d));
+                    if !schedule.is_active(group.major) && !(keep_default_open && holds_default) {
+                        group.is_expanded = false;
+                    }
+                }
+            }
+        }
+    }
+
+    pub(super) fn handle_environment_rename_started(&mut self, idx: usize) {
+        if let AppState::Main(state) = &mut self.state
+            && let Some(env) = state.environments.get(idx)
+        {
+            state.renaming_environment = Some((idx, env.name.clone()));
+        }
+    }
+
+    pub(super) fn handle_environment_rename_submitted(&mut self) {
+        if let AppState::Main(state) = &mut self.state
+            && let Some((idx, draft)) = state.renaming_environment.take()
+            && let Some(env) = state.environments.get_mut(idx)
+        {
+            let label = draft.trim();
+            let key = env.id.settings_key();
+            if label.is_empty() || label == env.id.display_name() {
+                self.settings.environment_labels.remove(&key);
+                env.name = env.id.display_name();
+            } else {
+                self.settings
+                    .environment_labels
+                    .insert(key, label.to_string());
+                env.name = label.to_string();
+            }
+            let _ = self.settings.save();
+            self.update_tray_menu();
+        }
+    }
+
     pub(super) fn handle_version_group_toggled(&mut self, major: u32) {
         if let AppState::Main(state) = &mut self.state {
             let env = state.active_environment_mut();
@@ -146,8 +421,79 @@ impl Versi {
         }
     }
 
+    pub(super) fn handle_minor_group_toggled(&mut self, major: u32, minor: u32) {
+        if let AppState::Main(state) = &mut self.state {
+            let env = state.active_environment_mut();
+            if !env.expanded_minors.remove(&(major, minor)) {
+                env.expanded_minors.insert((major, minor));
+            }
+        }
+    }
+
+    /// Sets the hovered row and lazily probes its bundled npm version the
+    /// first time it is hovered, so showing the list stays cheap while the
+    /// probe (a Node process per version) runs only for rows the user looks
+    /// at.
+    pub(super) fn handle_version_row_hovered(&mut self, version: Option<String>) -> Task<Message> {
+        if let AppState::Main(state) = &mut self.state {
+            if state.modal.is_some() {
+                state.hovered_version = None;
+                return Task::none();
+            }
+            state.hovered_version = version.clone();
+
+            if let Some(version) = version
+                && state.backend.capabilities().supports_exec
+                && !state
+                    .active_environment()
+                    .bundled_npm
+                    .contains_key(&version)
+            {
+                let env = state.active_environment_mut();
+                // Mark the probe as started so repeated hovers don't stack
+                // duplicate Node processes.
+                env.bundled_npm.insert(version.clone(), None);
+                let env_id = env.id.clone();
+                let backend = state.backend.clone();
+                return Task::perform(
+                    async move {
+                        let npm = backend.bundled_npm(&version).await.ok().flatten();
+                        Message::BundledNpmLoaded {
+                            env_id,
+                            version,
+                            npm,
+                        }
+                    },
+                    |msg| msg,
+                );
+            }
+        }
+        Task::none()
+    }
+
+    pub(super) fn handle_bundled_npm_loaded(
+        &mut self,
+        env_id: EnvironmentId,
+        version: String,
+        npm: Option<String>,
+    ) {
+        if let AppState::Main(state) = &mut self.state
+            && let Some(env) = state.environments.iter_mut().find(|e| e.id == env_id)
+        {
+            env.bundled_npm.insert(version, npm);
+        }
+    }
+
     pub(super) fn handle_search_changed(&mut self, query: String) {
         if let AppState::Main(state) = &mut self.state {
+            // Range resolution sees the normalized form (" v20 " → "20");
+            // the raw text stays in the search box untouched.
+            let normalized = crate::widgets::version_list::normalize_query(&query);
+            state.range_match = if versi_core::is_range_query(normalized) {
+                versi_core::resolve_range(normalized, &state.available_versions.versions).cloned()
+            } else {
+                None
+            };
             state.search_query = query;
         }
     }
@@ -27,35 +27,7 @@ impl Versi {
 
         if !result.backend_found {
             info!("No backend found, entering onboarding flow");
-            let shells = detect_shells();
-            debug!("Detected {} shells for configuration", shells.len());
-
-            let shell_statuses: Vec<ShellConfigStatus> = shells
-                .into_iter()
-                .map(|s| ShellConfigStatus {
-                    shell_type: s.shell_type.clone(),
-                    shell_name: s.shell_type.name().to_string(),
-                    configured: s.is_configured,
-                    config_path: s.config_file,
-                    configuring: false,
-                    error: None,
-                })
-                .collect();
-
-            let mut onboarding = OnboardingState::new();
-            onboarding.detected_shells = shell_statuses;
-
-            onboarding.available_backends = self
-                .providers
-                .values()
-                .map(|p| BackendOption {
-                    name: p.name(),
-                    display_name: p.display_name(),
-                    detected: false,
-                })
-                .collect();
-
-            self.state = AppState::Onboarding(onboarding);
+            self.state = AppState::Onboarding(self.build_onboarding_state());
             return Task::none();
         }
 
@@ -69,7 +41,13 @@ impl Versi {
         let backend_path = result
             .backend_path
             .unwrap_or_else(|| PathBuf::from(self.provider.name()));
-        let backend_dir = result.backend_dir;
+        // A user-set FNM_DIR override (validated when it was applied in
+        // settings) beats the detected data dir. If the directory has gone
+        // bad since, fall back to detection instead of wedging the backend.
+        let backend_dir = match &self.settings.fnm_dir {
+            Some(dir) if versi_core::validate_fnm_dir(dir).is_ok() => Some(dir.clone()),
+            _ => result.backend_dir,
+        };
 
         self.backend_path = backend_path.clone();
         self.backend_dir = backend_dir.clone();
@@ -87,7 +65,7 @@ impl Versi {
             .environments
             .iter()
             .map(|env_info| {
-                if env_info.available {
+                let mut env = if env_info.available {
                     EnvironmentState::new(
                         env_info.id.clone(),
                         env_info.backend_name,
@@ -102,13 +80,31 @@ impl Versi {
                             .as_deref()
                             .unwrap_or("Unavailable"),
                     )
+                };
+                if let (Some(provider), Some(version)) = (
+                    self.providers.get(env_info.backend_name),
+                    env_info.backend_version.as_deref(),
+                ) {
+                    env.progress_note = provider.progress_compatibility_note(version);
+                }
+                if let Some(label) = self
+                    .settings
+                    .environment_labels
+                    .get(&env_info.id.settings_key())
+                {
+                    env.name = label.clone();
                 }
+                env
             })
             .collect();
 
         let mut main_state =
             MainState::new_with_environments(backend, environments, active_backend_name);
         main_state.detected_backends = result.detected_backends;
+        main_state.project_pin = std::env::current_dir()
+            .ok()
+            .and_then(|dir| versi_core::read_project_pin(&dir))
+            .map(|(file, raw)| crate::state::ProjectPin { file, raw });
 
         if let Some(disk_cache) = crate::cache::DiskCache::load() {
             debug!(
@@ -128,57 +124,184 @@ impl Versi {
 
         self.state = AppState::Main(Box::new(main_state));
 
-        let mut load_tasks: Vec<Task<Message>> = Vec::new();
+        // Restore the previous session's search when the preference is on;
+        // going through the handler recomputes the range match too.
+        if self.settings.remember_search && !self.settings.last_search_query.is_empty() {
+            let query = self.settings.last_search_query.clone();
+            self.handle_search_changed(query);
+            self.search_restored = true;
+        }
 
-        for env_info in &result.environments {
-            if !env_info.available {
-                debug!(
-                    "Skipping load for unavailable environment: {:?}",
-                    env_info.id
-                );
-                continue;
-            }
+        self.pending_env_loads = result
+            .environments
+            .iter()
+            .filter(|env_info| env_info.available)
+            .map(|env_info| env_info.id.clone())
+            .collect();
+        self.active_env_loads.clear();
+
+        let mut load_tasks: Vec<Task<Message>> = vec![self.start_pending_environment_loads()];
+
+        // With "Only when window shown", a tray-minimized launch skips the
+        // remote fetch; the first ShowWindow releases it instead.
+        let start_hidden = self.settings.start_minimized
+            && self.settings.tray_behavior != crate::settings::TrayBehavior::Disabled;
+        let fetch_remote = if start_hidden
+            && self.settings.fetch_on_startup == crate::settings::FetchOnStartup::WhenShown
+        {
+            self.remote_fetch_deferred = true;
+            Task::none()
+        } else {
+            self.handle_fetch_remote_versions()
+        };
+        let fetch_schedule = self.handle_fetch_release_schedule();
+        let check_app_update = self.handle_check_for_app_update();
+        let check_backend_update = self.handle_check_for_backend_update();
+
+        load_tasks.extend([
+            fetch_remote,
+            fetch_schedule,
+            check_app_update,
+            check_backend_update,
+        ]);
+
+        Task::batch(load_tasks)
+    }
 
-            let env_id = env_info.id.clone();
-            let env_backend_name = env_info.backend_name;
+    fn build_onboarding_state(&self) -> OnboardingState {
+        let shells = detect_shells();
+        debug!("Detected {} shells for configuration", shells.len());
+
+        let shell_statuses: Vec<ShellConfigStatus> = shells
+            .into_iter()
+            .map(|s| ShellConfigStatus {
+                shell_type: s.shell_type.clone(),
+                shell_name: s.shell_type.name().to_string(),
+                configured: s.is_configured,
+                config_path: s.config_file,
+                configuring: false,
+                error: None,
+            })
+            .collect();
+
+        let mut onboarding = OnboardingState::new();
+        onboarding.detected_shells = shell_statuses;
+        onboarding.login_shell = versi_shell::login_shell();
+
+        onboarding.available_backends = self
+            .providers
+            .values()
+            .map(|p| BackendOption {
+                name: p.name(),
+                display_name: p.display_name(),
+                detected: false,
+            })
+            .collect();
+
+        onboarding
+    }
+
+    /// The backend binary vanished mid-session (e.g. `brew uninstall fnm`
+    /// while the app was open). Drop back into onboarding so the user can
+    /// reinstall without restarting the app.
+    pub(super) fn handle_backend_vanished(&mut self) -> Task<Message> {
+        if matches!(self.state, AppState::Onboarding(_)) {
+            return Task::none();
+        }
+
+        info!(
+            "Backend {} is no longer available, re-entering onboarding",
+            self.provider.name()
+        );
+
+        let mut onboarding = self.build_onboarding_state();
+        onboarding.vanished_backend = Some(self.provider.display_name());
+        self.state = AppState::Onboarding(onboarding);
+        Task::none()
+    }
+
+    /// Starts queued environment loads, up to the concurrency cap.
+    ///
+    /// Loading every environment at once fires one `wsl.exe` call per
+    /// distro simultaneously; staging the loads keeps startup cheap while
+    /// each tab still shows its own spinner until its load completes.
+    pub(super) fn start_pending_environment_loads(&mut self) -> Task<Message> {
+        let mut tasks: Vec<Task<Message>> = Vec::new();
+
+        while self.active_env_loads.len() < MAX_CONCURRENT_ENV_LOADS {
+            let Some(env_id) = self.pending_env_loads.pop_front() else {
+                break;
+            };
+
+            let backend_name = if let AppState::Main(state) = &self.state {
+                state
+                    .environments
+                    .iter()
+                    .find(|e| e.id == env_id)
+                    .map(|e| e.backend_name)
+            } else {
+                None
+            };
+            let Some(backend_name) = backend_name else {
+                continue;
+            };
 
             let provider = self
                 .providers
-                .get(env_backend_name)
+                .get(backend_name)
                 .cloned()
                 .unwrap_or_else(|| self.provider.clone());
 
-            let backend =
-                create_backend_for_environment(&env_id, &backend_path, &backend_dir, &provider);
+            let backend = create_backend_for_environment(
+                &env_id,
+                &self.backend_path,
+                &self.backend_dir,
+                &provider,
+            );
 
-            load_tasks.push(Task::perform(
+            debug!(
+                "Starting environment load for {:?} ({} pending)",
+                env_id,
+                self.pending_env_loads.len()
+            );
+            self.active_env_loads.insert(env_id.clone());
+
+            tasks.push(Task::perform(
                 async move {
+                    if let Err(e) = backend.probe().await {
+                        return Message::EnvironmentProbeFailed {
+                            env_id,
+                            reason: e.to_string(),
+                        };
+                    }
                     let versions = backend.list_installed().await.unwrap_or_default();
-                    (env_id, versions)
+                    let multishell = backend.current_version().await.ok().flatten();
+                    let aliases = backend.list_aliases().await.unwrap_or_default();
+                    Message::EnvironmentLoaded {
+                        env_id,
+                        versions,
+                        multishell,
+                        aliases,
+                    }
                 },
-                move |(env_id, versions)| Message::EnvironmentLoaded { env_id, versions },
+                |msg| msg,
             ));
         }
 
-        let fetch_remote = self.handle_fetch_remote_versions();
-        let fetch_schedule = self.handle_fetch_release_schedule();
-        let check_app_update = self.handle_check_for_app_update();
-        let check_backend_update = self.handle_check_for_backend_update();
-
-        load_tasks.extend([
-            fetch_remote,
-            fetch_schedule,
-            check_app_update,
-            check_backend_update,
-        ]);
-
-        Task::batch(load_tasks)
+        if tasks.is_empty() {
+            Task::none()
+        } else {
+            Task::batch(tasks)
+        }
     }
 }
 
+pub(super) const MAX_CONCURRENT_ENV_LOADS: usize = 2;
+
 pub(super) async fn initialize(
     providers: Vec<Arc<dyn BackendProvider>>,
     preferred: Option<String>,
+    manual_wsl: Vec<crate::settings::ManualWslEnvironment>,
 ) -> InitResult {
     info!(
         "Initializing application with {} providers...",
@@ -245,7 +368,10 @@ pub(super) async fn initialize(
     };
 
     #[cfg(not(windows))]
-    let environments = vec![native_env];
+    let environments = {
+        let _ = manual_wsl;
+        vec![native_env]
+    };
 
     #[cfg(windows)]
     let environments = {
@@ -321,6 +447,45 @@ pub(super) async fn initialize(
             }
         }
 
+        // Manually configured environments rescue distros where the backend
+        // sits outside the probed paths. Auto-detection wins when it already
+        // found a working backend for the same distro.
+        for manual in manual_wsl {
+            if envs.iter().any(|env| {
+                matches!(&env.id, EnvironmentId::Wsl { distro, .. } if *distro == manual.distro)
+                    && env.available
+            }) {
+                debug!(
+                    "Skipping manual WSL environment {}: already auto-detected",
+                    manual.distro
+                );
+                continue;
+            }
+            envs.retain(|env| {
+                !matches!(&env.id, EnvironmentId::Wsl { distro, .. } if *distro == manual.distro)
+            });
+            let wsl_backend_name =
+                determine_wsl_backend(&manual.backend_path, &provider_map, preferred_name);
+            let backend_version =
+                get_wsl_backend_version(&manual.distro, &manual.backend_path).await;
+            let available = backend_version.is_some();
+            info!(
+                "Adding manual WSL environment: {} ({} at {}, available={})",
+                manual.distro, wsl_backend_name, manual.backend_path, available
+            );
+            envs.push(EnvironmentInfo {
+                id: EnvironmentId::Wsl {
+                    distro: manual.distro,
+                    backend_path: manual.backend_path,
+                },
+                backend_name: wsl_backend_name,
+                backend_version,
+                available,
+                unavailable_reason: (!available)
+                    .then(|| "Backend not reachable at configured path".to_string()),
+            });
+        }
+
         envs
     };
 
@@ -372,12 +537,7 @@ async fn get_wsl_backend_version(distro: &str, backend_path: &str) -> Option<Str
         .ok()?;
 
     if output.status.success() {
-        let version_str = String::from_utf8_lossy(&output.stdout);
-        let version = version_str
-            .trim()
-            .strip_prefix("fnm ")
-            .unwrap_or(version_str.trim())
-            .to_string();
+        let version = versi_core::parse_fnm_version(&String::from_utf8_lossy(&output.stdout))?;
         debug!("WSL {} backend version: {}", distro, version);
         Some(version)
     } else {
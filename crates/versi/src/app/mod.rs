@@ -7,7 +7,7 @@ mod shell;
 mod tray_handlers;
 mod versions;
 
-use log::info;
+use log::{error, info};
 use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -17,17 +17,37 @@ use iced::{Element, Subscription, Task, Theme};
 use versi_backend::BackendProvider;
 
 use crate::message::Message;
-use crate::settings::{AppSettings, ThemeSetting, TrayBehavior};
-use crate::state::{AppState, MainViewKind};
-use crate::theme::{dark_theme, get_system_theme, light_theme};
+use crate::settings::{AppSettings, CloseAction, ThemeSetting, TrayBehavior};
+use crate::state::{AppState, MainViewKind, Toast};
+use crate::theme::{dark_theme_with, is_system_dark, light_theme_with, system_accent};
 use crate::tray;
 use crate::views;
 
+/// How many lines of the log file the in-app viewer shows.
+const LOG_VIEWER_TAIL_LINES: usize = 500;
+const BUG_REPORT_TAIL_LINES: usize = 50;
+/// Cap on the prefilled issue body before URL-encoding; browsers and GitHub
+/// reject very long URLs.
+const ISSUE_BODY_MAX_BYTES: usize = 4000;
+
 pub struct Versi {
     pub(crate) state: AppState,
     pub(crate) settings: AppSettings,
     pub(crate) window_id: Option<iced::window::Id>,
     pub(crate) pending_minimize: bool,
+    /// A search query restored from the previous session is still awaiting
+    /// its first environment load; if it then matches nothing it is cleared
+    /// rather than hiding the installed list behind a stale filter.
+    pub(crate) search_restored: bool,
+    /// Remote fetch deferred by the "only when window shown" startup
+    /// preference; released the first time the window becomes visible.
+    pub(crate) remote_fetch_deferred: bool,
+    /// Abort handle for the in-flight remote version fetch, so a hung fetch
+    /// can be canceled from the UI instead of spinning forever.
+    pub(crate) remote_fetch_abort: Option<iced::task::Handle>,
+    pub(crate) tray_menu_refreshed_at: Option<std::time::Instant>,
+    pub(crate) pending_env_loads: std::collections::VecDeque<versi_platform::EnvironmentId>,
+    pub(crate) active_env_loads: std::collections::HashSet<versi_platform::EnvironmentId>,
     pub(crate) backend_path: PathBuf,
     pub(crate) backend_dir: Option<PathBuf>,
     pub(crate) window_size: Option<iced::Size>,
@@ -65,6 +85,12 @@ impl Versi {
             settings,
             window_id: None,
             pending_minimize: should_minimize,
+            search_restored: false,
+            remote_fetch_deferred: false,
+            remote_fetch_abort: None,
+            tray_menu_refreshed_at: None,
+            pending_env_loads: std::collections::VecDeque::new(),
+            active_env_loads: std::collections::HashSet::new(),
             backend_path: PathBuf::from(active_provider.name()),
             backend_dir: None,
             window_size: None,
@@ -76,8 +102,9 @@ impl Versi {
 
         let all_providers: Vec<Arc<dyn BackendProvider>> = providers.values().cloned().collect();
         let preferred_backend = app.settings.preferred_backend.clone();
+        let manual_wsl = app.settings.manual_wsl_environments.clone();
         let init_task = Task::perform(
-            init::initialize(all_providers, preferred_backend),
+            init::initialize(all_providers, preferred_backend, manual_wsl),
             Message::Initialized,
         );
 
@@ -101,10 +128,30 @@ impl Versi {
     pub fn update(&mut self, message: Message) -> Task<Message> {
         match message {
             Message::Initialized(result) => self.handle_initialized(result),
-            Message::EnvironmentLoaded { env_id, versions } => {
-                self.handle_environment_loaded(env_id, versions)
+            Message::EnvironmentLoaded {
+                env_id,
+                versions,
+                multishell,
+                aliases,
+            } => self.handle_environment_loaded(env_id, versions, multishell, aliases),
+            Message::EnvironmentProbeFailed { env_id, reason } => {
+                self.handle_environment_probe_failed(env_id, reason)
+            }
+            Message::RefreshEnvironment => {
+                // The Cmd/Ctrl+R shortcut fires regardless of UI state;
+                // ignore it while a modal is open or a refresh is already
+                // running.
+                let blocked = if let AppState::Main(state) = &self.state {
+                    state.modal.is_some() || state.active_environment().loading
+                } else {
+                    false
+                };
+                if blocked {
+                    Task::none()
+                } else {
+                    self.handle_refresh_environment()
+                }
             }
-            Message::RefreshEnvironment => self.handle_refresh_environment(),
             Message::FocusSearch => {
                 if let AppState::Main(state) = &mut self.state {
                     state.view = MainViewKind::Versions;
@@ -117,11 +164,58 @@ impl Versi {
                 self.handle_version_group_toggled(major);
                 Task::none()
             }
+            Message::MinorGroupToggled { major, minor } => {
+                self.handle_minor_group_toggled(major, minor);
+                Task::none()
+            }
             Message::SearchChanged(query) => {
                 self.handle_search_changed(query);
                 Task::none()
             }
+            Message::OpenBrowseVersions => {
+                if let AppState::Main(state) = &mut self.state {
+                    state.modal = Some(crate::state::Modal::BrowseVersions {
+                        query: String::new(),
+                        filter: crate::state::BrowseFilter::All,
+                        limit: 100,
+                    });
+                }
+                Task::none()
+            }
+            Message::BrowseQueryChanged(value) => {
+                if let AppState::Main(state) = &mut self.state
+                    && let Some(crate::state::Modal::BrowseVersions { query, limit, .. }) =
+                        &mut state.modal
+                {
+                    *query = value;
+                    *limit = 100;
+                }
+                Task::none()
+            }
+            Message::BrowseFilterChanged(value) => {
+                if let AppState::Main(state) = &mut self.state
+                    && let Some(crate::state::Modal::BrowseVersions { filter, limit, .. }) =
+                        &mut state.modal
+                {
+                    *filter = value;
+                    *limit = 100;
+                }
+                Task::none()
+            }
+            Message::BrowseShowMore => {
+                if let AppState::Main(state) = &mut self.state
+                    && let Some(crate::state::Modal::BrowseVersions { limit, .. }) =
+                        &mut state.modal
+                {
+                    *limit += 100;
+                }
+                Task::none()
+            }
             Message::FetchRemoteVersions => self.handle_fetch_remote_versions(),
+            Message::CancelRemoteFetch => {
+                self.handle_cancel_remote_fetch();
+                Task::none()
+            }
             Message::RemoteVersionsFetched(result) => {
                 self.handle_remote_versions_fetched(result);
                 Task::none()
@@ -142,8 +236,9 @@ impl Versi {
                 }
                 Task::none()
             }
+            Message::ConfirmModal => self.handle_confirm_modal(),
             Message::OpenChangelog(version) => {
-                let url = format!("https://nodejs.org/en/blog/release/{}", version);
+                let url = self.settings.changelog_source.url_for(&version);
                 Task::perform(
                     async move {
                         let _ = open::that(&url);
@@ -161,7 +256,8 @@ impl Versi {
                 success,
                 error,
             } => self.handle_install_complete(version, success, error),
-            Message::RequestUninstall(version) => self.handle_uninstall(version),
+            Message::RequestUninstall(version) => self.handle_request_uninstall(version),
+            Message::ConfirmUninstall => self.handle_confirm_uninstall(),
             Message::UninstallComplete {
                 version,
                 success,
@@ -169,6 +265,7 @@ impl Versi {
             } => self.handle_uninstall_complete(version, success, error),
             Message::RequestBulkUpdateMajors => self.handle_request_bulk_update_majors(),
             Message::RequestBulkUninstallEOL => self.handle_request_bulk_uninstall_eol(),
+            Message::EolProjectScanCompleted(pins) => self.handle_eol_project_scan_completed(pins),
             Message::RequestBulkUninstallMajor { major } => {
                 self.handle_request_bulk_uninstall_major(major)
             }
@@ -183,13 +280,299 @@ impl Versi {
             Message::ConfirmBulkUninstallMajorExceptLatest { major } => {
                 self.handle_confirm_bulk_uninstall_major_except_latest(major)
             }
+            Message::InstallProjectPin => self.handle_install_project_pin(),
+            Message::ProjectPinWritten(result) => {
+                if let AppState::Main(state) = &mut self.state {
+                    match result {
+                        Ok(version) => {
+                            if let Some(pin) = &mut state.project_pin {
+                                pin.raw = version;
+                            }
+                        }
+                        Err(e) => {
+                            let toast_id = state.next_toast_id();
+                            state.add_toast(Toast::error(
+                                toast_id,
+                                format!("Failed to update project pin file: {}", e),
+                            ));
+                        }
+                    }
+                }
+                Task::none()
+            }
+            Message::ScanProjects => self.handle_scan_projects(),
+            Message::ProjectScanCompleted(pins) => {
+                self.handle_project_scan_completed(pins);
+                Task::none()
+            }
+            Message::ConfirmInstallFromProjects => self.handle_confirm_install_from_projects(),
+            Message::ProjectDirInputChanged(value) => {
+                if let AppState::Main(state) = &mut self.state {
+                    state.settings_state.project_dir_input = value;
+                }
+                Task::none()
+            }
+            Message::ProjectDirAdded => {
+                if let AppState::Main(state) = &mut self.state {
+                    let dir = state.settings_state.project_dir_input.trim();
+                    if !dir.is_empty() {
+                        let path = std::path::PathBuf::from(dir);
+                        if !self.settings.project_dirs.contains(&path) {
+                            self.settings.project_dirs.push(path);
+                            let _ = self.settings.save();
+                        }
+                        state.settings_state.project_dir_input.clear();
+                    }
+                }
+                Task::none()
+            }
+            Message::ProjectDirRemoved(index) => {
+                if index < self.settings.project_dirs.len() {
+                    self.settings.project_dirs.remove(index);
+                    let _ = self.settings.save();
+                }
+                Task::none()
+            }
+            Message::ManualWslDistroInputChanged(value) => {
+                if let AppState::Main(state) = &mut self.state {
+                    state.settings_state.manual_wsl_distro_input = value;
+                }
+                Task::none()
+            }
+            Message::ManualWslPathInputChanged(value) => {
+                if let AppState::Main(state) = &mut self.state {
+                    state.settings_state.manual_wsl_path_input = value;
+                }
+                Task::none()
+            }
+            Message::ManualWslEnvironmentAdded => {
+                if let AppState::Main(state) = &mut self.state {
+                    let distro = state.settings_state.manual_wsl_distro_input.trim();
+                    let path = state.settings_state.manual_wsl_path_input.trim();
+                    if !distro.is_empty() && !path.is_empty() {
+                        let entry = crate::settings::ManualWslEnvironment {
+                            distro: distro.to_string(),
+                            backend_path: path.to_string(),
+                        };
+                        if !self.settings.manual_wsl_environments.contains(&entry) {
+                            self.settings.manual_wsl_environments.push(entry);
+                            let _ = self.settings.save();
+                        }
+                        state.settings_state.manual_wsl_distro_input.clear();
+                        state.settings_state.manual_wsl_path_input.clear();
+                    }
+                }
+                Task::none()
+            }
+            Message::ManualWslEnvironmentRemoved(index) => {
+                if index < self.settings.manual_wsl_environments.len() {
+                    self.settings.manual_wsl_environments.remove(index);
+                    let _ = self.settings.save();
+                }
+                Task::none()
+            }
             Message::CancelBulkOperation => {
                 self.handle_close_modal();
                 Task::none()
             }
+            Message::ClearQueue => {
+                if let AppState::Main(state) = &mut self.state {
+                    state.operation_queue.clear_pending();
+                }
+                Task::none()
+            }
             Message::SetDefault(version) => self.handle_set_default(version),
-            Message::DefaultChanged { success, error } => {
-                self.handle_default_changed(success, error)
+            Message::DefaultChanged {
+                success,
+                error,
+                previous,
+            } => self.handle_default_changed(success, error, previous),
+            Message::UndoSetDefault => {
+                if let AppState::Main(state) = &mut self.state
+                    && let Some((_, previous)) = state.undo_default.take()
+                {
+                    self.handle_set_default(previous)
+                } else {
+                    Task::none()
+                }
+            }
+            Message::PinDefaultMajor { major } => self.handle_pin_default_major(major),
+            Message::UnpinDefaultMajor => {
+                self.handle_unpin_default_major();
+                Task::none()
+            }
+            Message::VersionRowDoubleClicked(version) => {
+                match self.settings.row_double_click_action {
+                    crate::settings::RowDoubleClickAction::SetDefault => {
+                        self.handle_set_default(version)
+                    }
+                    crate::settings::RowDoubleClickAction::Use => self.handle_use_version(version),
+                    crate::settings::RowDoubleClickAction::Changelog => {
+                        Task::done(Message::OpenChangelog(version))
+                    }
+                }
+            }
+            Message::UseVersionComplete(result) => {
+                self.handle_use_version_complete(result);
+                Task::none()
+            }
+            Message::OpenRunCommand(version) => {
+                self.handle_open_run_command(version);
+                Task::none()
+            }
+            Message::RunCommandInputChanged(value) => {
+                if let AppState::Main(state) = &mut self.state
+                    && let Some(crate::state::Modal::RunCommand { input, running, .. }) =
+                        &mut state.modal
+                    && !*running
+                {
+                    *input = value;
+                }
+                Task::none()
+            }
+            Message::RunCommandSubmitted => self.handle_run_command_submitted(),
+            Message::RunCommandCompleted(result) => {
+                self.handle_run_command_completed(result);
+                Task::none()
+            }
+            Message::RowDoubleClickActionChanged(action) => {
+                self.settings.row_double_click_action = action;
+                let _ = self.settings.save();
+                Task::none()
+            }
+            Message::ChangelogSourceChanged(source) => {
+                self.settings.changelog_source = source;
+                let _ = self.settings.save();
+                Task::none()
+            }
+            Message::GroupSortChanged(sort) => {
+                self.settings.group_sort = sort;
+                let _ = self.settings.save();
+                self.apply_group_sort_defaults();
+                Task::none()
+            }
+            Message::ProgressVerbosityChanged(verbosity) => {
+                self.settings.progress_verbosity = verbosity;
+                let _ = self.settings.save();
+                Task::none()
+            }
+            Message::FnmDirInputChanged(value) => {
+                if let AppState::Main(state) = &mut self.state {
+                    state.settings_state.fnm_dir_input = value;
+                    state.settings_state.fnm_dir_error = None;
+                }
+                Task::none()
+            }
+            Message::FnmDirSubmitted => {
+                if let AppState::Main(state) = &mut self.state {
+                    let dir = state.settings_state.fnm_dir_input.trim();
+                    if dir.is_empty() {
+                        // Clearing the input drops the override.
+                        self.settings.fnm_dir = None;
+                        let _ = self.settings.save();
+                        state.settings_state.fnm_dir_error = None;
+                    } else {
+                        let path = std::path::PathBuf::from(dir);
+                        match versi_core::validate_fnm_dir(&path) {
+                            Ok(()) => {
+                                self.settings.fnm_dir = Some(path);
+                                let _ = self.settings.save();
+                                state.settings_state.fnm_dir_error = None;
+                            }
+                            Err(e) => state.settings_state.fnm_dir_error = Some(e),
+                        }
+                    }
+                }
+                Task::none()
+            }
+            Message::CreateFnmDirStructure => {
+                if let AppState::Main(state) = &mut self.state {
+                    let path = std::path::PathBuf::from(state.settings_state.fnm_dir_input.trim());
+                    match versi_core::create_fnm_dir_structure(&path)
+                        .and_then(|()| versi_core::validate_fnm_dir(&path))
+                    {
+                        Ok(()) => {
+                            self.settings.fnm_dir = Some(path);
+                            let _ = self.settings.save();
+                            state.settings_state.fnm_dir_error = None;
+                        }
+                        Err(e) => state.settings_state.fnm_dir_error = Some(e),
+                    }
+                }
+                Task::none()
+            }
+            Message::ShowAllPatchesToggled(value) => {
+                self.settings.show_all_patches = value;
+                let _ = self.settings.save();
+                Task::none()
+            }
+            Message::GroupByMinorToggled(value) => {
+                self.settings.group_by_minor = value;
+                let _ = self.settings.save();
+                Task::none()
+            }
+            Message::GroupByCodenameToggled(value) => {
+                self.settings.group_by_codename = value;
+                let _ = self.settings.save();
+                self.regroup_environments();
+                Task::none()
+            }
+            Message::AutoExpandDefaultGroupToggled(value) => {
+                self.settings.auto_expand_default_group = value;
+                let _ = self.settings.save();
+                Task::none()
+            }
+            Message::SkipUninstallConfirmToggled(value) => {
+                self.settings.skip_uninstall_confirm = value;
+                let _ = self.settings.save();
+                Task::none()
+            }
+            Message::RefreshOnShowChanged(value) => {
+                self.settings.refresh_on_show = value;
+                let _ = self.settings.save();
+                Task::none()
+            }
+            Message::FetchOnStartupChanged(value) => {
+                self.settings.fetch_on_startup = value;
+                let _ = self.settings.save();
+                Task::none()
+            }
+            Message::InstallTimeoutChanged(minutes) => {
+                self.settings.install_timeout_mins = minutes;
+                let _ = self.settings.save();
+                Task::none()
+            }
+            Message::RememberSearchToggled(value) => {
+                self.settings.remember_search = value;
+                if !value {
+                    self.settings.last_search_query = String::new();
+                }
+                let _ = self.settings.save();
+                Task::none()
+            }
+            Message::IgnoredEolMajorInputChanged(value) => {
+                if let AppState::Main(state) = &mut self.state {
+                    state.settings_state.eol_pin_input = value;
+                }
+                Task::none()
+            }
+            Message::IgnoredEolMajorAdded => {
+                if let AppState::Main(state) = &mut self.state
+                    && let Ok(major) = state.settings_state.eol_pin_input.trim().parse::<u32>()
+                {
+                    state.settings_state.eol_pin_input.clear();
+                    if !self.settings.ignored_eol_majors.contains(&major) {
+                        self.settings.ignored_eol_majors.push(major);
+                        self.settings.ignored_eol_majors.sort_unstable();
+                        let _ = self.settings.save();
+                    }
+                }
+                Task::none()
+            }
+            Message::IgnoredEolMajorRemoved(major) => {
+                self.settings.ignored_eol_majors.retain(|m| *m != major);
+                let _ = self.settings.save();
+                Task::none()
             }
             Message::ToastDismiss(id) => {
                 if let AppState::Main(state) = &mut self.state {
@@ -207,16 +590,33 @@ impl Versi {
                 if let AppState::Main(state) = &mut self.state {
                     state.view = MainViewKind::Settings;
                     state.settings_state.checking_shells = true;
+                    if state.settings_state.fnm_dir_input.is_empty()
+                        && let Some(dir) = &self.settings.fnm_dir
+                    {
+                        state.settings_state.fnm_dir_input = dir.display().to_string();
+                    }
                 }
                 let shell_task = self.handle_check_shell_setup();
                 let log_stats_task = Task::perform(
                     async {
                         let log_path = versi_platform::AppPaths::new().log_file();
-                        std::fs::metadata(&log_path).ok().map(|m| m.len())
+                        let size = std::fs::metadata(&log_path).ok().map(|m| m.len());
+                        let backup_size =
+                            std::fs::metadata(crate::logging::rotated_log_path(&log_path))
+                                .ok()
+                                .map(|m| m.len());
+                        (size, backup_size)
                     },
-                    Message::LogFileStatsLoaded,
+                    |(size, backup_size)| Message::LogFileStatsLoaded { size, backup_size },
                 );
-                Task::batch([shell_task, log_stats_task])
+                let cache_size_task = match self.backend_cache_dir() {
+                    Some(dir) => Task::perform(
+                        async move { Some(versi_core::dir_size(&dir)) },
+                        Message::CacheSizeLoaded,
+                    ),
+                    None => Task::none(),
+                };
+                Task::batch([shell_task, log_stats_task, cache_size_task])
             }
             Message::NavigateToAbout => {
                 if let AppState::Main(state) = &mut self.state {
@@ -224,21 +624,31 @@ impl Versi {
                 }
                 Task::none()
             }
-            Message::VersionRowHovered(version) => {
+            Message::HeaderMenuToggled => {
                 if let AppState::Main(state) = &mut self.state {
-                    if state.modal.is_some() {
-                        state.hovered_version = None;
-                    } else {
-                        state.hovered_version = version;
-                    }
+                    state.header_menu_open = !state.header_menu_open;
                 }
                 Task::none()
             }
+            Message::VersionRowHovered(version) => self.handle_version_row_hovered(version),
+            Message::BundledNpmLoaded {
+                env_id,
+                version,
+                npm,
+            } => {
+                self.handle_bundled_npm_loaded(env_id, version, npm);
+                Task::none()
+            }
             Message::ThemeChanged(theme) => {
                 self.settings.theme = theme;
                 let _ = self.settings.save();
                 Task::none()
             }
+            Message::UseSystemAccentToggled(value) => {
+                self.settings.use_system_accent = value;
+                let _ = self.settings.save();
+                Task::none()
+            }
             Message::ShellOptionUseOnCdToggled(value) => {
                 self.settings.shell_options.use_on_cd = value;
                 let _ = self.settings.save();
@@ -263,7 +673,39 @@ impl Versi {
                 }
                 Task::none()
             }
+            Message::ProxyHostChanged(value) => {
+                self.settings.proxy.host = value;
+                let _ = self.settings.save();
+                Task::none()
+            }
+            Message::ProxyUsernameChanged(value) => {
+                self.settings.proxy.username = value;
+                let _ = self.settings.save();
+                Task::none()
+            }
+            Message::ProxyPasswordChanged(value) => {
+                self.settings.proxy.password = value;
+                let _ = self.settings.save();
+                Task::none()
+            }
             Message::CopyToClipboard(text) => iced::clipboard::write(text),
+            Message::CopyBugReport => self.handle_copy_bug_report(),
+            Message::BugReportAssembled(report) => iced::clipboard::write(report),
+            Message::ReportIssue => self.handle_report_issue(),
+            Message::CopyDockerfileLine(version) => {
+                let tag = version.trim_start_matches('v');
+                let line = format!(
+                    "FROM node:{}{}",
+                    tag,
+                    self.settings.docker_image_variant.tag_suffix()
+                );
+                iced::clipboard::write(line)
+            }
+            Message::DockerImageVariantChanged(variant) => {
+                self.settings.docker_image_variant = variant;
+                let _ = self.settings.save();
+                Task::none()
+            }
             Message::ClearLogFile => {
                 let log_path = versi_platform::AppPaths::new().log_file();
                 Task::perform(
@@ -271,6 +713,7 @@ impl Versi {
                         if log_path.exists() {
                             let _ = std::fs::write(&log_path, "");
                         }
+                        let _ = std::fs::remove_file(crate::logging::rotated_log_path(&log_path));
                     },
                     |_| Message::LogFileCleared,
                 )
@@ -278,9 +721,34 @@ impl Versi {
             Message::LogFileCleared => {
                 if let AppState::Main(state) = &mut self.state {
                     state.settings_state.log_file_size = Some(0);
+                    state.settings_state.log_backup_size = None;
                 }
                 Task::none()
             }
+            Message::RevealBackendDir => {
+                if let AppState::Main(state) = &self.state
+                    && let Some(dir) = state.backend.backend_info().data_dir.clone()
+                {
+                    Task::perform(
+                        async move { platform::reveal_in_file_manager(&dir) },
+                        |_| Message::NoOp,
+                    )
+                } else {
+                    Task::none()
+                }
+            }
+            Message::RevealVersionDir(version) => {
+                if let AppState::Main(state) = &self.state
+                    && let Some(dir) = state.backend.version_install_dir(&version)
+                {
+                    Task::perform(
+                        async move { platform::reveal_in_file_manager(&dir) },
+                        |_| Message::NoOp,
+                    )
+                } else {
+                    Task::none()
+                }
+            }
             Message::RevealLogFile => {
                 let log_path = versi_platform::AppPaths::new().log_file();
                 Task::perform(
@@ -288,13 +756,56 @@ impl Versi {
                     |_| Message::NoOp,
                 )
             }
-            Message::LogFileStatsLoaded(size) => {
+            Message::ViewLogFile => {
+                let log_path = versi_platform::AppPaths::new().log_file();
+                Task::perform(
+                    async move {
+                        versi_core::read_log_tail(&log_path, LOG_VIEWER_TAIL_LINES)
+                            .unwrap_or_else(|e| format!("Could not read log file: {}", e))
+                    },
+                    Message::LogFileTailLoaded,
+                )
+            }
+            Message::LogFileTailLoaded(content) => {
+                if let AppState::Main(state) = &mut self.state {
+                    state.modal = Some(crate::state::Modal::LogViewer { content });
+                }
+                Task::none()
+            }
+            Message::LogFileStatsLoaded { size, backup_size } => {
                 if let AppState::Main(state) = &mut self.state {
                     state.settings_state.log_file_size = size;
+                    state.settings_state.log_backup_size = backup_size;
+                }
+                Task::none()
+            }
+            Message::CacheSizeLoaded(size) => {
+                if let AppState::Main(state) = &mut self.state {
+                    state.settings_state.cache_size = size;
+                }
+                Task::none()
+            }
+            Message::RequestClearCache => {
+                if let AppState::Main(state) = &mut self.state
+                    && let Some(size) = state.settings_state.cache_size
+                {
+                    state.modal = Some(crate::state::Modal::ConfirmClearCache { size });
+                }
+                Task::none()
+            }
+            Message::ConfirmClearCache => self.handle_confirm_clear_cache(),
+            Message::CacheCleared => {
+                if let AppState::Main(state) = &mut self.state {
+                    state.settings_state.cache_size = Some(0);
+                }
+                Task::none()
+            }
+            Message::ShellFlagsUpdated(count) => {
+                if let AppState::Main(state) = &mut self.state {
+                    state.settings_state.shell_flags_updated = Some(count);
                 }
                 Task::none()
             }
-            Message::ShellFlagsUpdated => Task::none(),
             Message::ShellSetupChecked(results) => {
                 self.handle_shell_setup_checked(results);
                 Task::none()
@@ -304,7 +815,38 @@ impl Versi {
                 self.handle_shell_configured(shell_type, result);
                 Task::none()
             }
+            Message::ManualShellPathChanged(value) => {
+                if let AppState::Main(state) = &mut self.state {
+                    state.settings_state.manual_shell_path = value;
+                }
+                Task::none()
+            }
+            Message::ManualShellTypeSelected(shell_type) => {
+                if let AppState::Main(state) = &mut self.state {
+                    state.settings_state.manual_shell_type = shell_type;
+                }
+                Task::none()
+            }
+            Message::ManualShellConfigure => self.handle_manual_shell_configure(),
+            Message::ManualShellConfigured(shell_type, result) => {
+                self.handle_manual_shell_configured(shell_type, result);
+                Task::none()
+            }
             Message::PreferredBackendChanged(name) => self.handle_preferred_backend_changed(name),
+            Message::MigrateFromNvmRequested => self.handle_migrate_from_nvm_requested(),
+            Message::MigrateFromNvmListLoaded(result) => {
+                self.handle_migrate_from_nvm_list_loaded(result)
+            }
+            Message::MigrateFromNvmSetDefaultToggled(value) => {
+                if let AppState::Main(state) = &mut self.state
+                    && let Some(crate::state::Modal::ConfirmMigrateFromNvm { set_default, .. }) =
+                        &mut state.modal
+                {
+                    *set_default = value;
+                }
+                Task::none()
+            }
+            Message::ConfirmMigrateFromNvm => self.handle_confirm_migrate_from_nvm(),
             Message::OnboardingNext => self.handle_onboarding_next(),
             Message::OnboardingBack => {
                 self.handle_onboarding_back();
@@ -321,14 +863,28 @@ impl Versi {
             Message::OnboardingConfigureShell(shell_type) => {
                 self.handle_onboarding_configure_shell(shell_type)
             }
+            Message::OnboardingToggleAllShells => {
+                if let AppState::Onboarding(state) = &mut self.state {
+                    state.show_all_shells = !state.show_all_shells;
+                }
+                Task::none()
+            }
             Message::OnboardingShellConfigResult(result) => {
                 self.handle_onboarding_shell_config_result(result);
                 Task::none()
             }
+            Message::OnboardingInstallNode => self.handle_onboarding_install_node(),
+            Message::OnboardingNodeInstallProgress(progress) => {
+                self.handle_onboarding_node_install_progress(progress);
+                Task::none()
+            }
+            Message::OnboardingNodeInstallResult(result) => {
+                self.handle_onboarding_node_install_result(result)
+            }
             Message::OnboardingComplete => self.handle_onboarding_complete(),
             Message::AnimationTick => {
                 if let AppState::Main(state) = &mut self.state {
-                    let loading = state.active_environment().loading;
+                    let loading = state.environments.iter().any(|e| e.loading);
                     state.refresh_rotation += std::f32::consts::TAU / 40.0;
                     if !loading && state.refresh_rotation >= std::f32::consts::TAU {
                         state.refresh_rotation = 0.0;
@@ -345,20 +901,40 @@ impl Versi {
             Message::WindowEvent(iced::window::Event::CloseRequested)
             | Message::WindowEvent(iced::window::Event::Closed)
             | Message::CloseWindow => {
-                self.save_window_geometry();
-                if self.settings.tray_behavior == TrayBehavior::AlwaysRunning
-                    && tray::is_tray_active()
+                if self.settings.remember_search
+                    && let AppState::Main(state) = &self.state
+                    && self.settings.last_search_query != state.search_query
                 {
-                    if let Some(id) = self.window_id {
-                        platform::set_dock_visible(false);
-                        iced::window::set_mode(id, iced::window::Mode::Hidden)
-                    } else {
-                        Task::none()
+                    self.settings.last_search_query = state.search_query.clone();
+                    let _ = self.settings.save();
+                }
+                self.save_window_geometry();
+                match self.settings.close_action {
+                    CloseAction::Quit => self.quit_or_confirm(),
+                    CloseAction::MinimizeToTray => self.hide_window_to_tray(),
+                    CloseAction::Ask => {
+                        if let AppState::Main(state) = &mut self.state {
+                            state.modal = Some(crate::state::Modal::ConfirmClose);
+                            Task::none()
+                        } else {
+                            iced::exit()
+                        }
                     }
-                } else {
-                    iced::exit()
                 }
             }
+            Message::ConfirmCloseQuit => self.quit_or_confirm(),
+            Message::ConfirmQuitAnyway => iced::exit(),
+            Message::ConfirmQuitDuringOperationsToggled(value) => {
+                self.settings.confirm_quit_during_operations = value;
+                let _ = self.settings.save();
+                Task::none()
+            }
+            Message::ConfirmCloseMinimize => {
+                if let AppState::Main(state) = &mut self.state {
+                    state.modal = None;
+                }
+                self.hide_window_to_tray()
+            }
             Message::WindowEvent(iced::window::Event::Resized(size)) => {
                 self.window_size = Some(size);
                 Task::none()
@@ -369,30 +945,75 @@ impl Versi {
             }
             Message::WindowOpened(id) => {
                 self.window_id = Some(id);
+                let mut tasks = Vec::new();
+                if self.settings.always_on_top {
+                    tasks.push(iced::window::change_level(id, window_level(true)));
+                }
                 if self.pending_minimize {
                     self.pending_minimize = false;
-                    Task::batch([
-                        Task::done(Message::HideDockIcon),
-                        iced::window::set_mode(id, iced::window::Mode::Hidden),
-                    ])
-                } else {
-                    Task::none()
+                    tasks.push(Task::done(Message::HideDockIcon));
+                    tasks.push(iced::window::set_mode(id, iced::window::Mode::Hidden));
                 }
+                Task::batch(tasks)
             }
             Message::HideDockIcon => {
                 platform::set_dock_visible(false);
                 Task::none()
             }
             Message::WindowEvent(_) => Task::none(),
+            Message::CheckForAppUpdate => {
+                if let AppState::Main(state) = &mut self.state {
+                    state.settings_state.app_update_check =
+                        crate::state::UpdateCheckStatus::Checking;
+                }
+                self.handle_check_for_app_update()
+            }
+            Message::CheckForBackendUpdate => {
+                if let AppState::Main(state) = &mut self.state
+                    && state.active_environment().backend_version.is_some()
+                {
+                    state.settings_state.backend_update_check =
+                        crate::state::UpdateCheckStatus::Checking;
+                }
+                self.handle_check_for_backend_update()
+            }
             Message::AppUpdateChecked(result) => {
                 self.handle_app_update_checked(result);
                 Task::none()
             }
             Message::OpenAppUpdate => {
-                if let AppState::Main(state) = &self.state
+                if let AppState::Main(state) = &mut self.state
                     && let Some(update) = &state.app_update
                 {
+                    let version = update.latest_version.clone();
                     let url = update.release_url.clone();
+
+                    // In-place update when enabled and feasible; app-bundle
+                    // and read-only installs fall back to the release page.
+                    if self.settings.self_update && versi_core::self_update_supported() {
+                        state.modal = Some(crate::state::Modal::AppUpdateProgress {
+                            version: version.clone(),
+                            release_url: url,
+                            progress: versi_core::SelfUpdateProgress::Downloading { percent: None },
+                        });
+                        let client = self.http_client.clone();
+                        let update_stream = async_stream::stream! {
+                            let mut rx = versi_core::self_update(client, version);
+                            while let Some(progress) = rx.recv().await {
+                                let done = matches!(
+                                    progress,
+                                    versi_core::SelfUpdateProgress::Complete
+                                        | versi_core::SelfUpdateProgress::Failed(_)
+                                );
+                                yield Message::SelfUpdateProgressed(progress);
+                                if done {
+                                    break;
+                                }
+                            }
+                        };
+                        return Task::run(update_stream, |msg| msg);
+                    }
+
                     return Task::perform(
                         async move {
                             let _ = open::that(&url);
@@ -402,6 +1023,50 @@ impl Versi {
                 }
                 Task::none()
             }
+            Message::SelfUpdateToggled(value) => {
+                self.settings.self_update = value;
+                let _ = self.settings.save();
+                Task::none()
+            }
+            Message::SelfUpdateProgressed(progress) => {
+                if let AppState::Main(state) = &mut self.state {
+                    match &mut state.modal {
+                        Some(crate::state::Modal::AppUpdateProgress {
+                            progress: current, ..
+                        }) => *current = progress,
+                        // The modal was dismissed mid-download; a failure is
+                        // still worth a toast, a success simply takes effect
+                        // on the next launch.
+                        _ => {
+                            if let versi_core::SelfUpdateProgress::Failed(error) = progress {
+                                let toast_id = state.next_toast_id();
+                                state.add_toast(Toast::error(
+                                    toast_id,
+                                    format!("App update failed: {}", error),
+                                ));
+                            } else if matches!(progress, versi_core::SelfUpdateProgress::Complete) {
+                                state.app_update = None;
+                            }
+                        }
+                    }
+                }
+                Task::none()
+            }
+            Message::RestartForUpdate => {
+                if let Ok(exe) = std::env::current_exe() {
+                    let _ = std::process::Command::new(exe).spawn();
+                }
+                iced::exit()
+            }
+            Message::SkipAppUpdate => {
+                if let AppState::Main(state) = &mut self.state
+                    && let Some(update) = state.app_update.take()
+                {
+                    self.settings.skipped_app_version = Some(update.latest_version);
+                    let _ = self.settings.save();
+                }
+                Task::none()
+            }
             Message::BackendUpdateChecked(result) => {
                 self.handle_backend_update_checked(result);
                 Task::none()
@@ -427,14 +1092,45 @@ impl Versi {
                 },
                 |_| Message::NoOp,
             ),
+            Message::BackendVanished => self.handle_backend_vanished(),
             Message::EnvironmentSelected(idx) => self.handle_environment_selected(idx),
+            Message::EnvironmentRenameStarted(idx) => {
+                self.handle_environment_rename_started(idx);
+                Task::none()
+            }
+            Message::EnvironmentRenameChanged(draft) => {
+                if let AppState::Main(state) = &mut self.state
+                    && let Some((_, current)) = &mut state.renaming_environment
+                {
+                    *current = draft;
+                }
+                Task::none()
+            }
+            Message::EnvironmentRenameSubmitted => {
+                self.handle_environment_rename_submitted();
+                Task::none()
+            }
             Message::TrayEvent(tray_msg) => self.handle_tray_event(tray_msg),
             Message::TrayBehaviorChanged(behavior) => self.handle_tray_behavior_changed(behavior),
+            Message::CloseActionChanged(action) => {
+                self.settings.close_action = action;
+                let _ = self.settings.save();
+                Task::none()
+            }
             Message::StartMinimizedToggled(value) => {
                 self.settings.start_minimized = value;
                 let _ = self.settings.save();
                 Task::none()
             }
+            Message::AlwaysOnTopToggled(value) => {
+                self.settings.always_on_top = value;
+                let _ = self.settings.save();
+                if let Some(id) = self.window_id {
+                    iced::window::change_level(id, window_level(value))
+                } else {
+                    Task::none()
+                }
+            }
             _ => Task::none(),
         }
     }
@@ -450,9 +1146,26 @@ impl Versi {
                 views::onboarding::view(state, backend_name)
             }
             AppState::Main(state) => match state.view {
-                MainViewKind::Versions => views::main_view::view(state, &self.settings),
+                MainViewKind::Versions => views::main_view::view(
+                    state,
+                    &self.settings,
+                    self.window_size.map(|size| size.width),
+                ),
                 MainViewKind::Settings => {
-                    views::settings_view::view(&state.settings_state, &self.settings, state)
+                    let content =
+                        views::settings_view::view(&state.settings_state, &self.settings, state);
+                    // Modals opened from settings (log viewer, project scan
+                    // confirmation) overlay the settings view in place.
+                    if let Some(modal) = &state.modal {
+                        views::main_view::modals::modal_overlay(
+                            content,
+                            modal,
+                            state,
+                            &self.settings,
+                        )
+                    } else {
+                        content
+                    }
                 }
                 MainViewKind::About => views::about_view::view(state),
             },
@@ -460,15 +1173,77 @@ impl Versi {
     }
 
     pub fn theme(&self) -> Theme {
-        match self.settings.theme {
-            ThemeSetting::System => get_system_theme(),
-            ThemeSetting::Light => light_theme(),
-            ThemeSetting::Dark => dark_theme(),
+        let is_dark = match self.settings.theme {
+            ThemeSetting::System => is_system_dark(),
+            ThemeSetting::Light => false,
+            ThemeSetting::Dark => true,
+        };
+        let accent = if self.settings.use_system_accent {
+            system_accent(is_dark)
+        } else {
+            None
+        };
+        if is_dark {
+            dark_theme_with(accent)
+        } else {
+            light_theme_with(accent)
+        }
+    }
+
+    /// Where the backend keeps downloaded archives: `.cache` under its data
+    /// dir. `None` when the data dir is unknown, which hides the cache
+    /// controls entirely.
+    fn backend_cache_dir(&self) -> Option<std::path::PathBuf> {
+        if let AppState::Main(state) = &self.state {
+            state
+                .backend
+                .backend_info()
+                .data_dir
+                .as_ref()
+                .map(|dir| dir.join(".cache"))
+        } else {
+            None
+        }
+    }
+
+    fn handle_confirm_clear_cache(&mut self) -> Task<Message> {
+        if let AppState::Main(state) = &mut self.state {
+            state.modal = None;
+        }
+        match self.backend_cache_dir() {
+            Some(dir) => Task::perform(
+                async move {
+                    if let Err(e) = versi_core::clear_dir_contents(&dir) {
+                        error!("Failed to clear download cache: {}", e);
+                    }
+                },
+                |_| Message::CacheCleared,
+            ),
+            None => Task::none(),
+        }
+    }
+
+    /// Whether the one-second tick has any work to do. Dropping the timer
+    /// entirely while idle keeps a tray-resident app from waking the CPU
+    /// every second; adding a toast or starting an operation re-evaluates
+    /// the subscriptions and brings it back.
+    fn needs_tick(&self) -> bool {
+        if let AppState::Main(state) = &self.state {
+            !state.toasts.is_empty()
+                || !state.operation_queue.active_installs.is_empty()
+                || state.operation_queue.exclusive_op.is_some()
+                || !state.operation_queue.pending.is_empty()
+        } else {
+            false
         }
     }
 
     pub fn subscription(&self) -> Subscription<Message> {
-        let tick = iced::time::every(std::time::Duration::from_secs(1)).map(|_| Message::Tick);
+        let tick = if self.needs_tick() {
+            iced::time::every(std::time::Duration::from_secs(1)).map(|_| Message::Tick)
+        } else {
+            Subscription::none()
+        };
 
         let keyboard = iced::event::listen_with(|event, _status, _id| {
             if let iced::Event::Keyboard(iced::keyboard::Event::KeyPressed {
@@ -479,6 +1254,12 @@ impl Versi {
                     return Some(Message::CloseModal);
                 }
 
+                // Only fires the open confirm modal's primary action; with no
+                // modal open (e.g. Enter in the search box) it is a no-op.
+                if key == iced::keyboard::Key::Named(iced::keyboard::key::Named::Enter) {
+                    return Some(Message::ConfirmModal);
+                }
+
                 #[cfg(target_os = "macos")]
                 let cmd = modifiers.command();
                 #[cfg(not(target_os = "macos"))]
@@ -532,9 +1313,106 @@ impl Versi {
         ])
     }
 
+    /// App/OS/backend details, environments, and shell statuses — the
+    /// synchronous diagnostics shared by the clipboard bug report and the
+    /// prefilled issue link.
+    fn assemble_diagnostics(&self) -> String {
+        let mut report = String::new();
+        report.push_str(&format!("Versi {}\n", env!("CARGO_PKG_VERSION")));
+        report.push_str(&format!(
+            "OS: {} {}\n",
+            std::env::consts::OS,
+            std::env::consts::ARCH
+        ));
+
+        if let AppState::Main(state) = &self.state {
+            let info = state.backend.backend_info();
+            report.push_str(&format!(
+                "Backend: {} {} ({})\n",
+                info.name,
+                info.version.as_deref().unwrap_or("unknown"),
+                info.path.display()
+            ));
+
+            report.push_str("Environments:\n");
+            for env in &state.environments {
+                let status = if !env.available {
+                    env.error.as_deref().unwrap_or("unavailable").to_string()
+                } else {
+                    format!(
+                        "{} installed, backend {}",
+                        env.installed_versions.len(),
+                        env.backend_version.as_deref().unwrap_or("unknown")
+                    )
+                };
+                report.push_str(&format!("  {}: {}\n", env.name, status));
+            }
+
+            if !state.settings_state.shell_statuses.is_empty() {
+                report.push_str("Shells:\n");
+                for shell in &state.settings_state.shell_statuses {
+                    report.push_str(&format!("  {}: {:?}\n", shell.shell_name, shell.status));
+                }
+            }
+        }
+
+        report
+    }
+
+    /// Assembles a bug-report payload: the diagnostics plus a short log
+    /// tail, then copies it to the clipboard. The proxy password is
+    /// redacted before anything leaves the app.
+    fn handle_copy_bug_report(&self) -> Task<Message> {
+        let report = self.assemble_diagnostics();
+        let password = self.settings.proxy.password.clone();
+        let log_path = versi_platform::AppPaths::new().log_file();
+        Task::perform(
+            async move {
+                let tail = versi_core::read_log_tail(&log_path, BUG_REPORT_TAIL_LINES)
+                    .unwrap_or_else(|e| format!("Could not read log file: {}", e));
+                let tail = if password.is_empty() {
+                    tail
+                } else {
+                    tail.replace(&password, "[redacted]")
+                };
+                format!("{}\nLog tail:\n{}", report, tail)
+            },
+            Message::BugReportAssembled,
+        )
+    }
+
+    /// Opens the GitHub new-issue page with the diagnostics prefilled. The
+    /// log tail stays out: it would blow the URL length cap, so the body
+    /// asks for logs to be attached manually instead.
+    fn handle_report_issue(&self) -> Task<Message> {
+        let mut body = self.assemble_diagnostics();
+        if body.len() > ISSUE_BODY_MAX_BYTES {
+            let mut cut = ISSUE_BODY_MAX_BYTES;
+            while !body.is_char_boundary(cut) {
+                cut -= 1;
+            }
+            body.truncate(cut);
+            body.push_str("\n[truncated]");
+        }
+        body.push_str(
+            "\nPlease attach the debug log (Settings > Show in Folder) if relevant.\
+             \n\n## What happened?\n",
+        );
+        let url = format!(
+            "https://github.com/almeidx/versi/issues/new?body={}",
+            percent_encode(&body)
+        );
+        Task::perform(
+            async move {
+                let _ = open::that(&url);
+            },
+            |_| Message::NoOp,
+        )
+    }
+
     fn is_refresh_animating(&self) -> bool {
         if let AppState::Main(state) = &self.state {
-            state.refresh_rotation != 0.0
+            state.refresh_rotation != 0.0 || state.environments.iter().any(|e| e.loading)
         } else {
             false
         }
@@ -578,4 +1456,64 @@ impl Versi {
             let _ = self.settings.save();
         }
     }
+
+    /// Whether anything is running or queued that quitting would kill
+    /// mid-way, with the guard setting enabled.
+    fn quit_needs_confirmation(&self) -> bool {
+        self.settings.confirm_quit_during_operations
+            && matches!(
+                &self.state,
+                AppState::Main(state) if state.operation_queue.has_operations()
+            )
+    }
+
+    /// Quits, unless operations are in flight and the guard setting is on —
+    /// then a confirmation modal goes up instead so a stray Cmd+Q can't
+    /// leave partial installs behind.
+    fn quit_or_confirm(&mut self) -> Task<Message> {
+        if self.quit_needs_confirmation()
+            && let AppState::Main(state) = &mut self.state
+        {
+            state.modal = Some(crate::state::Modal::ConfirmQuitDuringOperations);
+            return Task::none();
+        }
+        iced::exit()
+    }
+
+    /// Hides the window, leaving the app reachable from the tray icon. Falls
+    /// back to quitting when no tray icon is active so the app cannot become
+    /// unreachable.
+    fn hide_window_to_tray(&self) -> Task<Message> {
+        if tray::is_tray_active()
+            && let Some(id) = self.window_id
+        {
+            platform::set_dock_visible(false);
+            iced::window::set_mode(id, iced::window::Mode::Hidden)
+        } else {
+            iced::exit()
+        }
+    }
+}
+
+fn window_level(always_on_top: bool) -> iced::window::Level {
+    if always_on_top {
+        iced::window::Level::AlwaysOnTop
+    } else {
+        iced::window::Level::Normal
+    }
+}
+
+/// Minimal percent-encoding for a URL query value; everything outside the
+/// unreserved set is escaped.
+fn percent_encode(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                out.push(byte as char)
+            }
+            _ => out.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    out
 }
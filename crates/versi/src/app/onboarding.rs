@@ -18,7 +18,8 @@ impl Versi {
                 }
                 OnboardingStep::SelectBackend => OnboardingStep::InstallBackend,
                 OnboardingStep::InstallBackend => OnboardingStep::ConfigureShell,
-                OnboardingStep::ConfigureShell => return self.handle_onboarding_complete(),
+                OnboardingStep::ConfigureShell => OnboardingStep::InstallNode,
+                OnboardingStep::InstallNode => return self.handle_onboarding_complete(),
             };
         }
         Task::none()
@@ -37,6 +38,7 @@ impl Versi {
                     }
                 }
                 OnboardingStep::ConfigureShell => OnboardingStep::InstallBackend,
+                OnboardingStep::InstallNode => OnboardingStep::ConfigureShell,
             };
         }
     }
@@ -108,13 +110,21 @@ impl Versi {
             let backend = self.provider.clone();
             let backend_marker = backend.shell_config_marker().to_string();
             let backend_label = backend.shell_config_label().to_string();
+            let override_path = self
+                .settings
+                .shell_config_overrides
+                .get(&shell_type)
+                .cloned();
 
             return Task::perform(
                 async move {
                     use versi_shell::{ShellConfig, get_or_create_config_path};
 
-                    let config_path = get_or_create_config_path(&shell_type)
-                        .ok_or_else(|| "No config file path found".to_string())?;
+                    let config_path = match override_path {
+                        Some(path) => path,
+                        None => get_or_create_config_path(&shell_type)
+                            .ok_or_else(|| "No config file path found".to_string())?,
+                    };
 
                     let mut config =
                         ShellConfig::load(shell_type, config_path).map_err(|e| e.to_string())?;
@@ -170,6 +180,115 @@ impl Versi {
         }
     }
 
+    /// One-click install of the newest LTS release, set as default when it
+    /// lands, so a fresh machine leaves onboarding with a working Node.
+    pub(super) fn handle_onboarding_install_node(&mut self) -> Task<Message> {
+        if let AppState::Onboarding(state) = &mut self.state {
+            state.node_installing = true;
+            state.node_install_progress = None;
+            state.node_install_error = None;
+
+            let provider = self.provider.clone();
+            let shell_options = self.settings.shell_options.clone();
+            let https_proxy = self.settings.proxy.proxy_url();
+            let timeout_secs = self.settings.install_timeout_secs();
+
+            let install_stream = async_stream::stream! {
+                let detection = provider.detect().await;
+                let manager = provider.create_manager(&detection);
+
+                let latest_lts = match manager.list_remote_lts().await {
+                    Ok(versions) => versions.into_iter().map(|v| v.version).max(),
+                    Err(e) => {
+                        yield Message::OnboardingNodeInstallResult(Err(e.to_string()));
+                        return;
+                    }
+                };
+                let Some(version) = latest_lts.map(|v| v.to_string()) else {
+                    yield Message::OnboardingNodeInstallResult(Err(
+                        "No LTS release found".to_string(),
+                    ));
+                    return;
+                };
+
+                let capabilities = manager.capabilities();
+                let options = versi_backend::InstallOptions {
+                    corepack_enabled: shell_options.corepack_enabled
+                        && capabilities.supports_corepack,
+                    resolve_engines: shell_options.resolve_engines
+                        && capabilities.supports_resolve_engines,
+                    https_proxy,
+                    timeout_secs,
+                };
+
+                match manager.install_with_progress(&version, &options).await {
+                    Ok(mut rx) => {
+                        let mut success = false;
+                        let mut error: Option<String> = None;
+                        while let Some(progress) = rx.recv().await {
+                            let is_complete =
+                                progress.phase == versi_backend::InstallPhase::Complete;
+                            let is_failed =
+                                progress.phase == versi_backend::InstallPhase::Failed;
+                            if is_failed {
+                                error = progress.error.clone();
+                            }
+                            yield Message::OnboardingNodeInstallProgress(progress);
+                            if is_complete {
+                                success = true;
+                                break;
+                            }
+                            if is_failed {
+                                break;
+                            }
+                        }
+                        if success {
+                            let result = manager
+                                .set_default(&version)
+                                .await
+                                .map_err(|e| e.to_string());
+                            yield Message::OnboardingNodeInstallResult(result);
+                        } else {
+                            yield Message::OnboardingNodeInstallResult(Err(error
+                                .unwrap_or_else(|| "Installation failed".to_string())));
+                        }
+                    }
+                    Err(e) => {
+                        yield Message::OnboardingNodeInstallResult(Err(e.to_string()));
+                    }
+                }
+            };
+            return Task::run(install_stream, |msg| msg);
+        }
+        Task::none()
+    }
+
+    pub(super) fn handle_onboarding_node_install_progress(
+        &mut self,
+        progress: versi_backend::InstallProgress,
+    ) {
+        if let AppState::Onboarding(state) = &mut self.state {
+            state.node_install_progress = Some(progress);
+        }
+    }
+
+    pub(super) fn handle_onboarding_node_install_result(
+        &mut self,
+        result: Result<(), String>,
+    ) -> Task<Message> {
+        if let AppState::Onboarding(state) = &mut self.state {
+            state.node_installing = false;
+            match result {
+                Ok(()) => return self.handle_onboarding_complete(),
+                Err(error) => {
+                    state.node_install_progress = None;
+                    state.node_install_error = Some(error);
+                }
+            }
+        }
+        Task::none()
+    }
+
     pub(super) fn handle_onboarding_complete(&mut self) -> Task<Message> {
         let all_providers = self.all_providers();
         let preferred = self.settings.preferred_backend.clone();
@@ -185,6 +304,7 @@ fn shell_type_to_str(shell_type: &versi_shell::ShellType) -> &'static str {
         versi_shell::ShellType::Bash => "bash",
         versi_shell::ShellType::Zsh => "zsh",
         versi_shell::ShellType::Fish => "fish",
+        versi_shell::ShellType::Nushell => "nushell",
         versi_shell::ShellType::PowerShell => "powershell",
         versi_shell::ShellType::Cmd => "cmd",
     }
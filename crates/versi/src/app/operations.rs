@@ -12,6 +12,35 @@ impl Versi {
         }
     }
 
+    /// Enter-to-confirm for the confirm modals. Informational modals (log
+    /// viewer, browse, run command) are left alone so Enter inside their
+    /// inputs doesn't trigger a destructive action.
+    pub(super) fn handle_confirm_modal(&mut self) -> Task<Message> {
+        let modal = if let AppState::Main(state) = &self.state {
+            state.modal.clone()
+        } else {
+            None
+        };
+
+        match modal {
+            Some(Modal::ConfirmUninstall { .. }) => self.handle_confirm_uninstall(),
+            Some(Modal::ConfirmBulkUpdateMajors { .. }) => self.handle_confirm_bulk_update_majors(),
+            Some(Modal::ConfirmInstallFromProjects { .. }) => {
+                self.handle_confirm_install_from_projects()
+            }
+            Some(Modal::ConfirmBulkUninstallEOL { .. }) => self.handle_confirm_bulk_uninstall_eol(),
+            Some(Modal::ConfirmMigrateFromNvm { .. }) => self.handle_confirm_migrate_from_nvm(),
+            Some(Modal::ConfirmBulkUninstallMajor { major, .. }) => {
+                self.handle_confirm_bulk_uninstall_major(major)
+            }
+            Some(Modal::ConfirmBulkUninstallMajorExceptLatest { major, .. }) => {
+                self.handle_confirm_bulk_uninstall_major_except_latest(major)
+            }
+            Some(Modal::ConfirmClearCache { .. }) => self.handle_confirm_clear_cache(),
+            _ => Task::none(),
+        }
+    }
+
     pub(super) fn handle_start_install(&mut self, version: String) -> Task<Message> {
         if let AppState::Main(state) = &mut self.state {
             state.modal = None;
@@ -26,21 +55,28 @@ impl Versi {
                 return Task::none();
             }
 
+            let env_id = state.active_environment().id.clone();
+
             if state.operation_queue.is_busy_for_install() {
                 state.operation_queue.pending.push_back(QueuedOperation {
                     request: OperationRequest::Install {
                         version: version.clone(),
                     },
+                    env_id,
                 });
                 return Task::none();
             }
 
-            return self.start_install_internal(version);
+            return self.start_install_internal(version, env_id);
         }
         Task::none()
     }
 
-    pub(super) fn start_install_internal(&mut self, version: String) -> Task<Message> {
+    pub(super) fn start_install_internal(
+        &mut self,
+        version: String,
+        env_id: versi_platform::EnvironmentId,
+    ) -> Task<Message> {
         if let AppState::Main(state) = &mut self.state {
             state
                 .operation_queue
@@ -48,13 +84,24 @@ impl Versi {
                 .push(Operation::Install {
                     version: version.clone(),
                     progress: Default::default(),
+                    env_id,
                 });
 
             let backend = state.backend.clone();
             let version_clone = version.clone();
 
+            let capabilities = backend.capabilities();
+            let install_options = versi_backend::InstallOptions {
+                corepack_enabled: self.settings.shell_options.corepack_enabled
+                    && capabilities.supports_corepack,
+                resolve_engines: self.settings.shell_options.resolve_engines
+                    && capabilities.supports_resolve_engines,
+                https_proxy: self.settings.proxy.proxy_url(),
+                timeout_secs: self.settings.install_timeout_secs(),
+            };
+
             let install_stream = async_stream::stream! {
-                match backend.install_with_progress(&version_clone).await {
+                match backend.install_with_progress(&version_clone, &install_options).await {
                     Ok(mut rx) => {
                         let mut final_success = false;
                         let mut last_error: Option<String> = None;
@@ -85,6 +132,9 @@ impl Versi {
                             error: if final_success { None } else { last_error.or_else(|| Some("Installation failed".to_string())) },
                         };
                     }
+                    Err(versi_backend::BackendError::BackendMissing) => {
+                        yield Message::BackendVanished;
+                    }
                     Err(e) => {
                         yield Message::InstallComplete {
                             version: version_clone.clone(),
@@ -99,6 +149,27 @@ impl Versi {
         Task::none()
     }
 
+    /// Installs the version resolved from the launch directory's pin file,
+    /// remembering it so the pin can be rewritten once the install lands.
+    pub(super) fn handle_install_project_pin(&mut self) -> Task<Message> {
+        let version = if let AppState::Main(state) = &mut self.state {
+            let Some(pin) = &state.project_pin else {
+                return Task::none();
+            };
+            let Some(resolved) =
+                versi_core::resolve_pin(&pin.raw, &state.available_versions.versions)
+            else {
+                return Task::none();
+            };
+            let version = resolved.version.to_string();
+            state.pin_install = Some(version.clone());
+            version
+        } else {
+            return Task::none();
+        };
+        self.handle_start_install(version)
+    }
+
     pub(super) fn handle_install_progress(
         &mut self,
         version: String,
@@ -109,6 +180,16 @@ impl Versi {
                 .operation_queue
                 .update_install_progress(&version, progress);
         }
+
+        // Keep the tray menu's progress labels current while the window may be
+        // hidden, throttled so frequent download updates don't rebuild the menu.
+        let should_refresh = self
+            .tray_menu_refreshed_at
+            .is_none_or(|at| at.elapsed() >= std::time::Duration::from_millis(500));
+        if should_refresh && crate::tray::is_tray_active() {
+            self.tray_menu_refreshed_at = Some(std::time::Instant::now());
+            self.update_tray_menu();
+        }
     }
 
     pub(super) fn handle_install_complete(
@@ -117,9 +198,49 @@ impl Versi {
         success: bool,
         error: Option<String>,
     ) -> Task<Message> {
+        let mut pin_task = Task::none();
         if let AppState::Main(state) = &mut self.state {
             state.operation_queue.remove_completed_install(&version);
 
+            // Remember when a version came from a non-default source (custom
+            // mirror or arch override), so the list can tag it as unofficial.
+            if success {
+                let info = state.backend.backend_info();
+                if info.node_dist_mirror.is_some() || info.arch.is_some() {
+                    self.settings.install_sources.insert(
+                        version.clone(),
+                        crate::settings::InstallSource {
+                            mirror: info.node_dist_mirror.clone(),
+                            arch: info.arch.clone(),
+                        },
+                    );
+                    let _ = self.settings.save();
+                }
+
+                // Show the new version immediately instead of waiting for the
+                // re-list below; the refresh is authoritative and replaces the
+                // optimistic entry when it lands.
+                if let Ok(parsed) = version.parse::<versi_backend::NodeVersion>() {
+                    let lts_codename = state
+                        .available_versions
+                        .versions
+                        .iter()
+                        .find(|r| r.version == parsed)
+                        .and_then(|r| r.lts_codename.clone());
+                    state.active_environment_mut().insert_optimistic(
+                        versi_backend::InstalledVersion {
+                            version: parsed,
+                            is_default: false,
+                            lts_codename,
+                            install_date: Some(chrono::Utc::now()),
+                            disk_size: None,
+                            arch: None,
+                        },
+                        self.settings.group_by_codename,
+                    );
+                }
+            }
+
             if !success {
                 let toast_id = state.next_toast_id();
                 state.add_toast(Toast::error(
@@ -131,33 +252,113 @@ impl Versi {
                     ),
                 ));
             }
+
+            if state.pin_install.as_deref() == Some(version.as_str()) {
+                state.pin_install = None;
+                // Rewrite the pin only when it was an alias or prefix; an
+                // already-exact pin is left untouched.
+                if success
+                    && let Some(pin) = &state.project_pin
+                    && pin.raw.trim_start_matches('v') != version.trim_start_matches('v')
+                {
+                    let file = pin.file.clone();
+                    let version_clone = version.clone();
+                    pin_task = Task::perform(
+                        async move {
+                            match versi_core::write_project_version(&file, &version_clone) {
+                                Ok(()) => Ok(version_clone),
+                                Err(e) => Err(e.to_string()),
+                            }
+                        },
+                        Message::ProjectPinWritten,
+                    );
+                }
+            }
         }
 
         let next_task = self.process_next_operation();
         let refresh_task = self.handle_refresh_environment();
-        Task::batch([refresh_task, next_task])
+        Task::batch([refresh_task, next_task, pin_task])
+    }
+
+    pub(super) fn handle_request_uninstall(&mut self, version: String) -> Task<Message> {
+        // Power users can opt out of the single-version confirmation; bulk
+        // uninstalls always keep theirs since those are higher-impact.
+        if self.settings.skip_uninstall_confirm {
+            return self.handle_uninstall(version);
+        }
+        if let AppState::Main(state) = &mut self.state {
+            let env = state.active_environment();
+            let is_default = env
+                .default_version
+                .as_ref()
+                .is_some_and(|d| d.to_string() == version);
+            let is_last = env.installed_versions.len() == 1;
+            let is_in_use = env
+                .multishell_version
+                .as_ref()
+                .is_some_and(|m| m.to_string() == version);
+            let disk_size = env
+                .installed_versions
+                .iter()
+                .find(|v| v.version.to_string() == version)
+                .and_then(|v| v.disk_size);
+            let install_path = state
+                .backend
+                .backend_info()
+                .data_dir
+                .as_ref()
+                .map(|dir| dir.join("node-versions").join(&version));
+
+            state.modal = Some(Modal::ConfirmUninstall {
+                version,
+                is_default,
+                is_last,
+                is_in_use,
+                install_path,
+                disk_size,
+            });
+        }
+        Task::none()
+    }
+
+    pub(super) fn handle_confirm_uninstall(&mut self) -> Task<Message> {
+        if let AppState::Main(state) = &mut self.state
+            && let Some(Modal::ConfirmUninstall { version, .. }) = state.modal.take()
+        {
+            return self.handle_uninstall(version);
+        }
+        Task::none()
     }
 
     pub(super) fn handle_uninstall(&mut self, version: String) -> Task<Message> {
         if let AppState::Main(state) = &mut self.state {
+            let env_id = state.active_environment().id.clone();
+
             if state.operation_queue.is_busy_for_exclusive() {
                 state.operation_queue.pending.push_back(QueuedOperation {
                     request: OperationRequest::Uninstall {
                         version: version.clone(),
                     },
+                    env_id,
                 });
                 return Task::none();
             }
 
-            return self.start_uninstall_internal(version);
+            return self.start_uninstall_internal(version, env_id);
         }
         Task::none()
     }
 
-    pub(super) fn start_uninstall_internal(&mut self, version: String) -> Task<Message> {
+    pub(super) fn start_uninstall_internal(
+        &mut self,
+        version: String,
+        env_id: versi_platform::EnvironmentId,
+    ) -> Task<Message> {
         if let AppState::Main(state) = &mut self.state {
             state.operation_queue.exclusive_op = Some(Operation::Uninstall {
                 version: version.clone(),
+                env_id,
             });
 
             let backend = state.backend.clone();
@@ -166,15 +367,33 @@ impl Versi {
             return Task::perform(
                 async move {
                     match backend.uninstall(&version_clone).await {
-                        Ok(()) => (version_clone, true, None),
-                        Err(e) => (version_clone, false, Some(e.to_string())),
+                        Ok(()) => Message::UninstallComplete {
+                            version: version_clone,
+                            success: true,
+                            error: None,
+                        },
+                        Err(versi_backend::BackendError::BackendMissing) => {
+                            Message::BackendVanished
+                        }
+                        Err(versi_backend::BackendError::UninstallBlockedByDefault) => {
+                            Message::UninstallComplete {
+                                version: version_clone,
+                                success: false,
+                                error: Some(
+                                    "it's the current default. Set a different default first, \
+                                     then retry"
+                                        .to_string(),
+                                ),
+                            }
+                        }
+                        Err(e) => Message::UninstallComplete {
+                            version: version_clone,
+                            success: false,
+                            error: Some(e.to_string()),
+                        },
                     }
                 },
-                |(version, success, error)| Message::UninstallComplete {
-                    version,
-                    success,
-                    error,
-                },
+                |msg| msg,
             );
         }
         Task::none()
@@ -186,8 +405,13 @@ impl Versi {
         success: bool,
         error: Option<String>,
     ) -> Task<Message> {
+        let mut op_env_id = None;
         if let AppState::Main(state) = &mut self.state {
-            state.operation_queue.exclusive_op = None;
+            if let Some(Operation::Uninstall { env_id, .. }) =
+                state.operation_queue.exclusive_op.take()
+            {
+                op_env_id = Some(env_id);
+            }
 
             if !success {
                 let toast_id = state.next_toast_id();
@@ -203,54 +427,249 @@ impl Versi {
         }
 
         let next_task = self.process_next_operation();
-        let refresh_task = self.handle_refresh_environment();
+        // Refresh the environment the operation actually ran against; the
+        // user may have switched tabs while it was in flight.
+        let refresh_task = match op_env_id {
+            Some(env_id) => self.refresh_environment_by_id(env_id),
+            None => self.handle_refresh_environment(),
+        };
         Task::batch([refresh_task, next_task])
     }
 
     pub(super) fn handle_set_default(&mut self, version: String) -> Task<Message> {
         if let AppState::Main(state) = &mut self.state {
+            let env_id = state.active_environment().id.clone();
+
+            // An explicit exact version replaces any floating major pin. The
+            // pin's own bare-major requests don't parse as a full version, so
+            // they pass through with the pin intact.
+            if version.parse::<versi_backend::NodeVersion>().is_ok()
+                && self
+                    .settings
+                    .pinned_default_majors
+                    .remove(&env_id.settings_key())
+                    .is_some()
+            {
+                let _ = self.settings.save();
+            }
+
             if state.operation_queue.is_busy_for_exclusive() {
                 state.operation_queue.pending.push_back(QueuedOperation {
                     request: OperationRequest::SetDefault {
                         version: version.clone(),
                     },
+                    env_id,
                 });
                 return Task::none();
             }
 
-            return self.start_set_default_internal(version);
+            return self.start_set_default_internal(version, env_id);
         }
         Task::none()
     }
 
-    pub(super) fn start_set_default_internal(&mut self, version: String) -> Task<Message> {
+    /// Pins the default to a major line. The backend gets the bare major
+    /// (`fnm default 20`) and resolves it to the newest matching installed
+    /// version; [`Self::handle_environment_loaded`] re-applies the pin when a
+    /// newer release of the major lands, so the default keeps floating.
+    pub(super) fn handle_pin_default_major(&mut self, major: u32) -> Task<Message> {
+        let AppState::Main(state) = &self.state else {
+            return Task::none();
+        };
+        let key = state.active_environment().id.settings_key();
+        self.settings.pinned_default_majors.insert(key, major);
+        let _ = self.settings.save();
+        self.handle_set_default(major.to_string())
+    }
+
+    /// Removes the floating pin; the default stays at whatever version it
+    /// last resolved to.
+    pub(super) fn handle_unpin_default_major(&mut self) {
+        if let AppState::Main(state) = &self.state {
+            let key = state.active_environment().id.settings_key();
+            if self.settings.pinned_default_majors.remove(&key).is_some() {
+                let _ = self.settings.save();
+            }
+        }
+    }
+
+    pub(super) fn start_set_default_internal(
+        &mut self,
+        version: String,
+        env_id: versi_platform::EnvironmentId,
+    ) -> Task<Message> {
         if let AppState::Main(state) = &mut self.state {
             state.operation_queue.exclusive_op = Some(Operation::SetDefault {
                 version: version.clone(),
+                env_id,
             });
+            state.undo_default = None;
+
+            // Bare-major pin requests stay out of last_used, which is keyed
+            // by full version strings.
+            if version.parse::<versi_backend::NodeVersion>().is_ok() {
+                self.settings
+                    .last_used
+                    .insert(version.clone(), std::time::SystemTime::now());
+                let _ = self.settings.save();
+            }
 
             let backend = state.backend.clone();
 
             return Task::perform(
                 async move {
+                    // Read the outgoing default here rather than at queue
+                    // time; an earlier queued change may have moved it since.
+                    let previous = backend
+                        .default_version()
+                        .await
+                        .ok()
+                        .flatten()
+                        .map(|v| v.to_string())
+                        .filter(|prev| prev != &version);
+
                     match backend.set_default(&version).await {
-                        Ok(()) => (true, None),
-                        Err(e) => (false, Some(e.to_string())),
+                        Ok(()) => Message::DefaultChanged {
+                            success: true,
+                            error: None,
+                            previous,
+                        },
+                        Err(versi_backend::BackendError::BackendMissing) => {
+                            Message::BackendVanished
+                        }
+                        Err(e) => Message::DefaultChanged {
+                            success: false,
+                            error: Some(e.to_string()),
+                            previous: None,
+                        },
+                    }
+                },
+                |msg| msg,
+            );
+        }
+        Task::none()
+    }
+
+    pub(super) fn handle_use_version(&mut self, version: String) -> Task<Message> {
+        if let AppState::Main(state) = &mut self.state {
+            self.settings
+                .last_used
+                .insert(version.clone(), std::time::SystemTime::now());
+            let _ = self.settings.save();
+
+            let backend = state.backend.clone();
+
+            return Task::perform(
+                async move {
+                    match backend.use_version(&version).await {
+                        Ok(()) => Message::UseVersionComplete(Ok(())),
+                        Err(versi_backend::BackendError::BackendMissing) => {
+                            Message::BackendVanished
+                        }
+                        Err(e) => Message::UseVersionComplete(Err(e.to_string())),
+                    }
+                },
+                |msg| msg,
+            );
+        }
+        Task::none()
+    }
+
+    pub(super) fn handle_open_run_command(&mut self, version: String) {
+        if let AppState::Main(state) = &mut self.state {
+            state.modal = Some(Modal::RunCommand {
+                version,
+                input: String::new(),
+                running: false,
+                output: None,
+            });
+        }
+    }
+
+    pub(super) fn handle_run_command_submitted(&mut self) -> Task<Message> {
+        if let AppState::Main(state) = &mut self.state
+            && let Some(Modal::RunCommand {
+                version,
+                input,
+                running,
+                output,
+            }) = &mut state.modal
+            && !*running
+        {
+            let args: Vec<String> = input.split_whitespace().map(str::to_string).collect();
+            if args.is_empty() {
+                return Task::none();
+            }
+
+            *running = true;
+            *output = None;
+
+            let backend = state.backend.clone();
+            let version = version.clone();
+
+            return Task::perform(
+                async move {
+                    match backend.exec(&version, &args).await {
+                        Ok(out) => Message::RunCommandCompleted(Ok(out)),
+                        Err(versi_backend::BackendError::BackendMissing) => {
+                            Message::BackendVanished
+                        }
+                        Err(e) => Message::RunCommandCompleted(Err(e.to_string())),
                     }
                 },
-                |(success, error)| Message::DefaultChanged { success, error },
+                |msg| msg,
             );
         }
         Task::none()
     }
 
+    pub(super) fn handle_run_command_completed(
+        &mut self,
+        result: Result<versi_backend::ExecOutput, String>,
+    ) {
+        if let AppState::Main(state) = &mut self.state
+            && let Some(Modal::RunCommand {
+                running, output, ..
+            }) = &mut state.modal
+        {
+            *running = false;
+            *output = Some(result);
+        }
+    }
+
+    pub(super) fn handle_use_version_complete(&mut self, result: Result<(), String>) {
+        if let AppState::Main(state) = &mut self.state
+            && let Err(error) = result
+        {
+            let toast_id = state.next_toast_id();
+            state.add_toast(Toast::error(
+                toast_id,
+                format!("Failed to use version: {}", error),
+            ));
+        }
+    }
+
     pub(super) fn handle_default_changed(
         &mut self,
         success: bool,
         error: Option<String>,
+        previous: Option<String>,
     ) -> Task<Message> {
+        let mut op_env_id = None;
         if let AppState::Main(state) = &mut self.state {
-            state.operation_queue.exclusive_op = None;
+            if let Some(Operation::SetDefault { version, env_id }) =
+                state.operation_queue.exclusive_op.take()
+            {
+                // Undo runs against the active backend, so only offer it when
+                // the operation's environment is still the active one.
+                if success
+                    && state.active_environment().id == env_id
+                    && let Some(prev) = previous
+                {
+                    state.undo_default = Some((version, prev));
+                }
+                op_env_id = Some(env_id);
+            }
 
             if !success {
                 let toast_id = state.next_toast_id();
@@ -262,7 +681,12 @@ impl Versi {
         }
 
         let next_task = self.process_next_operation();
-        let refresh_task = self.handle_refresh_environment();
+        // Refresh the environment the operation actually ran against; the
+        // user may have switched tabs while it was in flight.
+        let refresh_task = match op_env_id {
+            Some(env_id) => self.refresh_environment_by_id(env_id),
+            None => self.handle_refresh_environment(),
+        };
         Task::batch([refresh_task, next_task])
     }
 
@@ -331,20 +755,70 @@ impl Versi {
     }
 
     pub(super) fn handle_request_bulk_uninstall_eol(&mut self) -> Task<Message> {
+        // A scanned project may pin an EOL major; scan first so the modal
+        // can exclude those versions and say which project needs them.
+        if !self.settings.project_dirs.is_empty() {
+            let dirs = self.settings.project_dirs.clone();
+            return Task::perform(
+                async move { versi_core::scan_projects_with_paths(&dirs) },
+                Message::EolProjectScanCompleted,
+            );
+        }
+        self.handle_eol_project_scan_completed(Vec::new())
+    }
+
+    pub(super) fn handle_eol_project_scan_completed(
+        &mut self,
+        pins: Vec<(std::path::PathBuf, String)>,
+    ) -> Task<Message> {
         if let AppState::Main(state) = &mut self.state {
             let env = state.active_environment();
             let schedule = state.available_versions.schedule.as_ref();
+            let remote = &state.available_versions.versions;
 
-            let eol_versions: Vec<String> = env
-                .installed_versions
-                .iter()
-                .filter(|v| {
-                    schedule
-                        .map(|s| !s.is_active(v.version.major))
-                        .unwrap_or(false)
-                })
-                .map(|v| v.version.to_string())
-                .collect();
+            // Major -> first project directory that pins it. Pins resolve
+            // against the remote list; when that fails (offline, alias the
+            // list doesn't cover), the leading number of the pin still
+            // identifies the major.
+            let mut required: std::collections::BTreeMap<u32, String> =
+                std::collections::BTreeMap::new();
+            for (dir, pin) in &pins {
+                let major = versi_core::resolve_pin(pin, remote)
+                    .map(|v| v.version.major)
+                    .or_else(|| {
+                        pin.trim()
+                            .trim_start_matches('v')
+                            .split('.')
+                            .next()?
+                            .parse()
+                            .ok()
+                    });
+                if let Some(major) = major {
+                    required
+                        .entry(major)
+                        .or_insert_with(|| dir.display().to_string());
+                }
+            }
+
+            let mut eol_versions: Vec<String> = Vec::new();
+            let mut kept: Vec<(String, String)> = Vec::new();
+            for v in &env.installed_versions {
+                let is_eol = schedule
+                    .map(|s| !s.is_active(v.version.major))
+                    .unwrap_or(false)
+                    && !self.settings.ignored_eol_majors.contains(&v.version.major);
+                if !is_eol {
+                    continue;
+                }
+                if let Some(dir) = required.get(&v.version.major) {
+                    let label = format!("{}.x", v.version.major);
+                    if !kept.iter().any(|(existing, _)| existing == &label) {
+                        kept.push((label, dir.clone()));
+                    }
+                } else {
+                    eol_versions.push(v.version.to_string());
+                }
+            }
 
             if eol_versions.is_empty() {
                 return Task::none();
@@ -352,6 +826,7 @@ impl Versi {
 
             state.modal = Some(Modal::ConfirmBulkUninstallEOL {
                 versions: eol_versions,
+                kept,
             });
         }
         Task::none()
@@ -381,11 +856,163 @@ impl Versi {
         if let AppState::Main(state) = &mut self.state
             && let Some(Modal::ConfirmBulkUpdateMajors { versions }) = state.modal.take()
         {
+            let env_id = state.active_environment().id.clone();
             for (_from, to) in versions {
                 state.operation_queue.pending.push_back(QueuedOperation {
                     request: OperationRequest::Install {
                         version: to.clone(),
                     },
+                    env_id: env_id.clone(),
+                });
+            }
+            return self.process_next_operation();
+        }
+        Task::none()
+    }
+
+    pub(super) fn handle_scan_projects(&mut self) -> Task<Message> {
+        if self.settings.project_dirs.is_empty() {
+            return Task::none();
+        }
+
+        let dirs = self.settings.project_dirs.clone();
+        Task::perform(
+            async move { versi_core::scan_projects(&dirs) },
+            Message::ProjectScanCompleted,
+        )
+    }
+
+    pub(super) fn handle_project_scan_completed(&mut self, pins: Vec<String>) {
+        if let AppState::Main(state) = &mut self.state {
+            let env = state.active_environment();
+            let remote = &state.available_versions.versions;
+
+            let mut versions: Vec<String> = Vec::new();
+            for pin in &pins {
+                let Some(resolved) = versi_core::resolve_pin(pin, remote) else {
+                    continue;
+                };
+                let already_installed = env
+                    .installed_versions
+                    .iter()
+                    .any(|v| v.version == resolved.version);
+                let version = resolved.version.to_string();
+                if !already_installed && !versions.contains(&version) {
+                    versions.push(version);
+                }
+            }
+
+            if versions.is_empty() {
+                return;
+            }
+            versions.sort();
+
+            state.modal = Some(Modal::ConfirmInstallFromProjects { versions });
+        }
+    }
+
+    pub(super) fn handle_confirm_install_from_projects(&mut self) -> Task<Message> {
+        if let AppState::Main(state) = &mut self.state
+            && let Some(Modal::ConfirmInstallFromProjects { versions }) = state.modal.take()
+        {
+            let env_id = state.active_environment().id.clone();
+            for version in versions {
+                state.operation_queue.pending.push_back(QueuedOperation {
+                    request: OperationRequest::Install { version },
+                    env_id: env_id.clone(),
+                });
+            }
+            return self.process_next_operation();
+        }
+        Task::none()
+    }
+
+    /// Reads the versions installed under nvm so the user can bring them
+    /// over to fnm. The listing runs against a freshly detected nvm manager;
+    /// versions fnm already has are filtered out before the confirm modal.
+    pub(super) fn handle_migrate_from_nvm_requested(&mut self) -> Task<Message> {
+        let Some(provider) = self.providers.get("nvm").cloned() else {
+            return Task::none();
+        };
+
+        if let AppState::Main(state) = &self.state {
+            let installed: Vec<String> = state
+                .active_environment()
+                .installed_versions
+                .iter()
+                .map(|v| v.version.to_string())
+                .collect();
+
+            return Task::perform(
+                async move {
+                    let detection = provider.detect().await;
+                    if !detection.found {
+                        return Err("nvm is no longer installed".to_string());
+                    }
+                    let manager = provider.create_manager(&detection);
+                    let versions = manager.list_installed().await.map_err(|e| e.to_string())?;
+                    let default_version = versions
+                        .iter()
+                        .find(|v| v.is_default)
+                        .map(|v| v.version.to_string());
+                    let to_install: Vec<String> = versions
+                        .iter()
+                        .map(|v| v.version.to_string())
+                        .filter(|v| !installed.contains(v))
+                        .collect();
+                    Ok((to_install, default_version))
+                },
+                Message::MigrateFromNvmListLoaded,
+            );
+        }
+        Task::none()
+    }
+
+    pub(super) fn handle_migrate_from_nvm_list_loaded(
+        &mut self,
+        result: Result<(Vec<String>, Option<String>), String>,
+    ) -> Task<Message> {
+        if let AppState::Main(state) = &mut self.state {
+            match result {
+                Ok((versions, default_version)) => {
+                    state.modal = Some(Modal::ConfirmMigrateFromNvm {
+                        versions,
+                        set_default: default_version.is_some(),
+                        default_version,
+                    });
+                }
+                Err(e) => {
+                    let toast_id = state.next_toast_id();
+                    state.add_toast(Toast::error(
+                        toast_id,
+                        format!("Couldn't read nvm versions: {}", e),
+                    ));
+                }
+            }
+        }
+        Task::none()
+    }
+
+    pub(super) fn handle_confirm_migrate_from_nvm(&mut self) -> Task<Message> {
+        if let AppState::Main(state) = &mut self.state
+            && let Some(Modal::ConfirmMigrateFromNvm {
+                versions,
+                default_version,
+                set_default,
+            }) = state.modal.take()
+        {
+            let env_id = state.active_environment().id.clone();
+            for version in versions {
+                state.operation_queue.pending.push_back(QueuedOperation {
+                    request: OperationRequest::Install { version },
+                    env_id: env_id.clone(),
+                });
+            }
+            // Queued last so it runs after the installs it may depend on.
+            if set_default && let Some(version) = default_version {
+                state.operation_queue.pending.push_back(QueuedOperation {
+                    request: OperationRequest::SetDefault { version },
+                    env_id,
                 });
             }
             return self.process_next_operation();
@@ -395,11 +1022,13 @@ impl Versi {
 
     pub(super) fn handle_confirm_bulk_uninstall_eol(&mut self) -> Task<Message> {
         if let AppState::Main(state) = &mut self.state
-            && let Some(Modal::ConfirmBulkUninstallEOL { versions }) = state.modal.take()
+            && let Some(Modal::ConfirmBulkUninstallEOL { versions, .. }) = state.modal.take()
         {
+            let env_id = state.active_environment().id.clone();
             for version in versions {
                 state.operation_queue.pending.push_back(QueuedOperation {
                     request: OperationRequest::Uninstall { version },
+                    env_id: env_id.clone(),
                 });
             }
             return self.process_next_operation();
@@ -413,9 +1042,11 @@ impl Versi {
                 state.modal.take()
             && m == major
         {
+            let env_id = state.active_environment().id.clone();
             for version in versions {
                 state.operation_queue.pending.push_back(QueuedOperation {
                     request: OperationRequest::Uninstall { version },
+                    env_id: env_id.clone(),
                 });
             }
             return self.process_next_operation();
@@ -472,9 +1103,11 @@ impl Versi {
             }) = state.modal.take()
             && m == major
         {
+            let env_id = state.active_environment().id.clone();
             for version in versions {
                 state.operation_queue.pending.push_back(QueuedOperation {
                     request: OperationRequest::Uninstall { version },
+                    env_id: env_id.clone(),
                 });
             }
             return self.process_next_operation();
@@ -488,8 +1121,8 @@ impl Versi {
                 return Task::none();
             }
 
-            let mut install_versions: Vec<String> = Vec::new();
-            let mut exclusive_request: Option<OperationRequest> = None;
+            let mut install_versions: Vec<(String, versi_platform::EnvironmentId)> = Vec::new();
+            let mut exclusive_request: Option<QueuedOperation> = None;
 
             while let Some(next) = state.operation_queue.pending.front() {
                 match &next.request {
@@ -497,8 +1130,8 @@ impl Versi {
                         let already_active = state.operation_queue.active_installs.iter().any(
                             |op| matches!(op, Operation::Install { version: v, .. } if v == version),
                         );
-                        if !already_active && !install_versions.contains(version) {
-                            install_versions.push(version.clone());
+                        if !already_active && !install_versions.iter().any(|(v, _)| v == version) {
+                            install_versions.push((version.clone(), next.env_id.clone()));
                         }
                         state.operation_queue.pending.pop_front();
                     }
@@ -507,7 +1140,7 @@ impl Versi {
                             && install_versions.is_empty()
                             && let Some(queued) = state.operation_queue.pending.pop_front()
                         {
-                            exclusive_request = Some(queued.request);
+                            exclusive_request = Some(queued);
                         }
                         break;
                     }
@@ -515,16 +1148,16 @@ impl Versi {
             }
 
             let mut tasks: Vec<Task<Message>> = Vec::new();
-            for version in install_versions {
-                tasks.push(self.start_install_internal(version));
+            for (version, env_id) in install_versions {
+                tasks.push(self.start_install_internal(version, env_id));
             }
-            if let Some(request) = exclusive_request {
-                match request {
+            if let Some(queued) = exclusive_request {
+                match queued.request {
                     OperationRequest::Uninstall { version } => {
-                        tasks.push(self.start_uninstall_internal(version));
+                        tasks.push(self.start_uninstall_internal(version, queued.env_id));
                     }
                     OperationRequest::SetDefault { version } => {
-                        tasks.push(self.start_set_default_internal(version));
+                        tasks.push(self.start_set_default_internal(version, queued.env_id));
                     }
                     OperationRequest::Install { .. } => unreachable!(),
                 }
@@ -130,16 +130,24 @@ impl Versi {
         let provider = self.provider.clone();
         let marker = provider.shell_config_marker().to_string();
         let label = provider.shell_config_label().to_string();
+        let override_path = self
+            .settings
+            .shell_config_overrides
+            .get(&shell_type)
+            .cloned();
 
         let shell_type_for_callback = shell_type.clone();
         Task::perform(
             async move {
                 use versi_shell::{ShellConfig, get_or_create_config_path};
 
-                let config_path = get_or_create_config_path(&shell_type)
-                    .ok_or_else(|| "No config file path found".to_string())?;
+                let config_path = match override_path {
+                    Some(path) => path,
+                    None => get_or_create_config_path(&shell_type)
+                        .ok_or_else(|| "No config file path found".to_string())?,
+                };
 
-                let mut config = ShellConfig::load(shell_type.clone(), config_path)
+                let mut config = ShellConfig::load(shell_type.clone(), config_path.clone())
                     .map_err(|e| e.to_string())?;
 
                 if config.has_init(&marker) {
@@ -165,7 +173,7 @@ impl Versi {
                     }
                 }
 
-                Ok::<_, String>(())
+                Ok::<_, String>(config_path)
             },
             move |result| Message::ShellConfigured(shell_type_for_callback.clone(), result),
         )
@@ -174,20 +182,125 @@ impl Versi {
     pub(super) fn handle_shell_configured(
         &mut self,
         shell_type: versi_shell::ShellType,
-        result: Result<(), String>,
+        result: Result<std::path::PathBuf, String>,
     ) {
-        if let AppState::Main(state) = &mut self.state
-            && let Some(shell) = state
+        if let AppState::Main(state) = &mut self.state {
+            if let Some(shell) = state
                 .settings_state
                 .shell_statuses
                 .iter_mut()
                 .find(|s| s.shell_type == shell_type)
-        {
-            shell.configuring = false;
-            match result {
-                Ok(()) => shell.status = ShellVerificationStatus::Configured,
-                Err(_) => shell.status = ShellVerificationStatus::Error,
+            {
+                shell.configuring = false;
+                match &result {
+                    Ok(_) => shell.status = ShellVerificationStatus::Configured,
+                    Err(_) => shell.status = ShellVerificationStatus::Error,
+                }
+            }
+            if let Ok(config_path) = result {
+                state.settings_state.restart_hint = Some(restart_hint(&shell_type, &config_path));
+            }
+        }
+    }
+
+    /// Configures a shell against a user-supplied config file path, for
+    /// setups that auto-detection misses.
+    pub(super) fn handle_manual_shell_configure(&mut self) -> Task<Message> {
+        let (path, shell_type) = {
+            let AppState::Main(state) = &mut self.state else {
+                return Task::none();
+            };
+            let settings_state = &mut state.settings_state;
+            let path = settings_state.manual_shell_path.trim().to_string();
+            if path.is_empty() {
+                return Task::none();
+            }
+            settings_state.manual_shell_result = None;
+            (path, settings_state.manual_shell_type.clone())
+        };
+
+        let options = ShellInitOptions {
+            use_on_cd: self.settings.shell_options.use_on_cd,
+            resolve_engines: self.settings.shell_options.resolve_engines,
+            corepack_enabled: self.settings.shell_options.corepack_enabled,
+        };
+
+        let provider = self.provider.clone();
+        let marker = provider.shell_config_marker().to_string();
+        let label = provider.shell_config_label().to_string();
+
+        let shell_type_for_callback = shell_type.clone();
+        Task::perform(
+            async move {
+                use versi_shell::ShellConfig;
+
+                let config_path =
+                    expand_home(&path).ok_or_else(|| "Home directory not found".to_string())?;
+                if !config_path.is_file() {
+                    return Err(format!("{} is not a file", config_path.display()));
+                }
+
+                let mut config = ShellConfig::load(shell_type.clone(), config_path)
+                    .map_err(|e| e.to_string())?;
+
+                if config.has_init(&marker) {
+                    let edit = config.update_flags(&marker, &options);
+                    if edit.has_changes() {
+                        config.apply_edit(&edit).map_err(|e| e.to_string())?;
+                    }
+                } else {
+                    let init_command = provider
+                        .create_manager(&versi_backend::BackendDetection {
+                            found: true,
+                            path: None,
+                            version: None,
+                            in_path: true,
+                            data_dir: None,
+                        })
+                        .shell_init_command(shell_type.shell_arg(), &options)
+                        .ok_or_else(|| "Shell not supported".to_string())?;
+
+                    let edit = config.add_init(&init_command, &label);
+                    if edit.has_changes() {
+                        config.apply_edit(&edit).map_err(|e| e.to_string())?;
+                    }
+                }
+
+                Ok::<_, String>(())
+            },
+            move |result| Message::ManualShellConfigured(shell_type_for_callback.clone(), result),
+        )
+    }
+
+    pub(super) fn handle_manual_shell_configured(
+        &mut self,
+        shell_type: versi_shell::ShellType,
+        result: Result<(), String>,
+    ) {
+        if let AppState::Main(state) = &mut self.state {
+            let settings_state = &mut state.settings_state;
+            if result.is_ok() {
+                // Remember the path so future `Configure` runs for this shell
+                // target the same file instead of the auto-detected one.
+                if let Some(path) = expand_home(settings_state.manual_shell_path.trim()) {
+                    self.settings
+                        .shell_config_overrides
+                        .insert(shell_type.clone(), path);
+                    let _ = self.settings.save();
+                }
+                settings_state.restart_hint = Some(restart_hint(
+                    &shell_type,
+                    std::path::Path::new(settings_state.manual_shell_path.trim()),
+                ));
+                settings_state.manual_shell_path.clear();
+                settings_state.shell_statuses.push(ShellSetupStatus {
+                    shell_name: shell_type.name().to_string(),
+                    shell_type,
+                    status: ShellVerificationStatus::Configured,
+                    configuring: false,
+                });
             }
+            settings_state.manual_shell_result = Some(result);
         }
     }
 
@@ -205,6 +318,7 @@ impl Versi {
                 use versi_shell::ShellConfig;
 
                 let shells = detect_shells();
+                let mut updated = 0usize;
 
                 for shell in shells {
                     if let Some(config_path) = shell.config_file
@@ -216,12 +330,47 @@ impl Versi {
                         if edit.has_changes() {
                             config.apply_edit(&edit).map_err(|e| e.to_string())?;
                         }
+                        updated += 1;
                     }
                 }
 
-                Ok::<_, String>(())
+                Ok::<_, String>(updated)
             },
-            |_| Message::ShellFlagsUpdated,
+            |result| Message::ShellFlagsUpdated(result.unwrap_or(0)),
         )
     }
 }
+
+/// Expands a leading `~/` against the home directory; other paths pass
+/// through unchanged. `None` when `~` is used but no home is known.
+fn expand_home(path: &str) -> Option<std::path::PathBuf> {
+    match path.strip_prefix("~/") {
+        Some(rest) => dirs::home_dir().map(|home| home.join(rest)),
+        None => Some(std::path::PathBuf::from(path)),
+    }
+}
+
+/// Reminder shown after a shell config edit: existing terminals won't pick
+/// the change up until they re-source the file or restart.
+fn restart_hint(shell_type: &versi_shell::ShellType, config_path: &std::path::Path) -> String {
+    let display = match dirs::home_dir().and_then(|home| {
+        config_path
+            .strip_prefix(&home)
+            .ok()
+            .map(|rest| format!("~/{}", rest.display()))
+    }) {
+        Some(short) => short,
+        None => config_path.display().to_string(),
+    };
+
+    let source_command = match shell_type {
+        versi_shell::ShellType::PowerShell => Some(format!(". {}", display)),
+        versi_shell::ShellType::Cmd => None,
+        _ => Some(format!("source {}", display)),
+    };
+
+    match source_command {
+        Some(cmd) => format!("Restart your terminal or run `{}` to apply", cmd),
+        None => "Restart your terminal to apply".to_string(),
+    }
+}
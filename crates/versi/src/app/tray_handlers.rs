@@ -3,7 +3,7 @@ use log::error;
 use iced::Task;
 
 use crate::message::Message;
-use crate::settings::TrayBehavior;
+use crate::settings::{RefreshOnShow, TrayBehavior};
 use crate::state::{AppState, MainViewKind};
 use crate::tray::{self, TrayMenuData, TrayMessage};
 
@@ -19,8 +19,14 @@ impl Versi {
                     platform::set_dock_visible(true);
 
                     let needs_refresh = if let AppState::Main(state) = &self.state {
-                        state.active_environment().installed_versions.is_empty()
-                            && !state.active_environment().loading
+                        let env = state.active_environment();
+                        match self.settings.refresh_on_show {
+                            RefreshOnShow::Always => !env.loading,
+                            RefreshOnShow::IfEmpty => {
+                                env.installed_versions.is_empty() && !env.loading
+                            }
+                            RefreshOnShow::Never => false,
+                        }
                     } else {
                         false
                     };
@@ -34,6 +40,7 @@ impl Versi {
                     if needs_refresh {
                         tasks.push(Task::done(Message::RefreshEnvironment));
                     }
+                    tasks.push(self.take_deferred_remote_fetch());
 
                     Task::batch(tasks)
                 } else {
@@ -55,15 +62,21 @@ impl Versi {
                 } else {
                     Task::none()
                 };
+                let fetch_task = self.take_deferred_remote_fetch();
                 let shell_task = self.handle_check_shell_setup();
                 let log_stats_task = Task::perform(
                     async {
                         let log_path = versi_platform::AppPaths::new().log_file();
-                        std::fs::metadata(&log_path).ok().map(|m| m.len())
+                        let size = std::fs::metadata(&log_path).ok().map(|m| m.len());
+                        let backup_size =
+                            std::fs::metadata(crate::logging::rotated_log_path(&log_path))
+                                .ok()
+                                .map(|m| m.len());
+                        (size, backup_size)
                     },
-                    Message::LogFileStatsLoaded,
+                    |(size, backup_size)| Message::LogFileStatsLoaded { size, backup_size },
                 );
-                Task::batch([show_task, shell_task, log_stats_task])
+                Task::batch([show_task, fetch_task, shell_task, log_stats_task])
             }
             TrayMessage::OpenAbout => {
                 if let AppState::Main(state) = &mut self.state {
@@ -75,12 +88,33 @@ impl Versi {
                         iced::window::set_mode(id, iced::window::Mode::Windowed),
                         iced::window::minimize(id, false),
                         iced::window::gain_focus(id),
+                        self.take_deferred_remote_fetch(),
                     ])
                 } else {
                     Task::none()
                 }
             }
-            TrayMessage::Quit => iced::exit(),
+            TrayMessage::Quit => {
+                // The confirmation modal is useless behind a hidden window, so
+                // a guarded tray quit brings the window up along with it.
+                let task = self.quit_or_confirm();
+                if let AppState::Main(state) = &self.state
+                    && matches!(
+                        state.modal,
+                        Some(crate::state::Modal::ConfirmQuitDuringOperations)
+                    )
+                    && let Some(id) = self.window_id
+                {
+                    platform::set_dock_visible(true);
+                    return Task::batch([
+                        task,
+                        iced::window::set_mode(id, iced::window::Mode::Windowed),
+                        iced::window::minimize(id, false),
+                        iced::window::gain_focus(id),
+                    ]);
+                }
+                task
+            }
             TrayMessage::SetDefault { env_index, version } => {
                 if let AppState::Main(state) = &mut self.state
                     && env_index != state.active_environment_idx
@@ -120,8 +154,12 @@ impl Versi {
 
     pub(super) fn update_tray_menu(&self) {
         if let AppState::Main(state) = &self.state {
-            let data = TrayMenuData::from_environments(&state.environments);
+            let data = TrayMenuData::from_environments(&state.environments, &state.operation_queue);
             tray::update_menu(&data);
+            tray::set_tooltip(&tray::health_tooltip(
+                state.active_environment(),
+                &state.available_versions.versions,
+            ));
         }
     }
 }
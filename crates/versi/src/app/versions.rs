@@ -10,6 +10,11 @@ use crate::state::AppState;
 
 use super::Versi;
 
+/// Cap on the whole remote fetch including retries. A hung request would
+/// otherwise keep the fetch spinning forever; past this it resolves into the
+/// cached-list fallback instead.
+const REMOTE_FETCH_TIMEOUT_SECS: u64 = 30;
+
 impl Versi {
     pub(super) fn handle_fetch_remote_versions(&mut self) -> Task<Message> {
         if let AppState::Main(state) = &mut self.state {
@@ -20,38 +25,82 @@ impl Versi {
 
             let backend = state.backend.clone();
 
-            return Task::perform(
+            let (task, handle) = Task::perform(
                 async move {
-                    let delays = [0, 2, 5, 15];
-                    let mut last_err = String::new();
-                    for (attempt, &delay) in delays.iter().enumerate() {
-                        if delay > 0 {
-                            tokio::time::sleep(std::time::Duration::from_secs(delay)).await;
-                        }
-                        match backend.list_remote().await {
-                            Ok(versions) => return Ok(versions),
-                            Err(e) => {
-                                last_err = e.to_string();
-                                debug!(
-                                    "Remote versions fetch attempt {} failed: {}",
-                                    attempt + 1,
-                                    last_err
-                                );
+                    let fetch = async {
+                        let delays = [0, 2, 5, 15];
+                        let mut last_err = String::new();
+                        for (attempt, &delay) in delays.iter().enumerate() {
+                            if delay > 0 {
+                                tokio::time::sleep(std::time::Duration::from_secs(delay)).await;
+                            }
+                            match backend.list_remote().await {
+                                Ok(versions) => return Ok(versions),
+                                Err(e) => {
+                                    last_err = e.to_string();
+                                    debug!(
+                                        "Remote versions fetch attempt {} failed: {}",
+                                        attempt + 1,
+                                        last_err
+                                    );
+                                }
                             }
                         }
+                        Err(last_err)
+                    };
+                    match tokio::time::timeout(
+                        std::time::Duration::from_secs(REMOTE_FETCH_TIMEOUT_SECS),
+                        fetch,
+                    )
+                    .await
+                    {
+                        Ok(result) => result,
+                        Err(_) => Err(format!(
+                            "timed out after {} seconds",
+                            REMOTE_FETCH_TIMEOUT_SECS
+                        )),
                     }
-                    Err(last_err)
                 },
                 Message::RemoteVersionsFetched,
-            );
+            )
+            .abortable();
+            self.remote_fetch_abort = Some(handle);
+            return task;
         }
         Task::none()
     }
 
+    /// User-initiated bail-out of a hung remote fetch: aborts the in-flight
+    /// task and falls back to whatever list is already cached.
+    pub(super) fn handle_cancel_remote_fetch(&mut self) {
+        if let Some(handle) = self.remote_fetch_abort.take() {
+            handle.abort();
+        }
+        if let AppState::Main(state) = &mut self.state
+            && state.available_versions.loading
+        {
+            state.available_versions.loading = false;
+            state.available_versions.error =
+                Some("Fetch canceled \u{2014} showing the cached list".to_string());
+        }
+    }
+
+    /// Releases the remote fetch deferred by the "only when window shown"
+    /// startup preference. No-op once fired or when nothing was deferred.
+    pub(super) fn take_deferred_remote_fetch(&mut self) -> Task<Message> {
+        if self.remote_fetch_deferred {
+            self.remote_fetch_deferred = false;
+            self.handle_fetch_remote_versions()
+        } else {
+            Task::none()
+        }
+    }
+
     pub(super) fn handle_remote_versions_fetched(
         &mut self,
         result: Result<Vec<versi_backend::RemoteVersion>, String>,
     ) {
+        self.remote_fetch_abort = None;
         if let AppState::Main(state) = &mut self.state {
             state.available_versions.loading = false;
             match result {
@@ -136,6 +185,7 @@ impl Versi {
                 }
             }
         }
+        self.apply_group_sort_defaults();
     }
 
     pub(super) fn handle_check_for_app_update(&mut self) -> Task<Message> {
@@ -152,9 +202,36 @@ impl Versi {
         result: Result<Option<versi_core::AppUpdate>, String>,
     ) {
         if let AppState::Main(state) = &mut self.state {
+            let was_manual =
+                state.settings_state.app_update_check == crate::state::UpdateCheckStatus::Checking;
             match result {
-                Ok(update) => state.app_update = update,
-                Err(e) => debug!("App update check failed: {}", e),
+                Ok(update) => {
+                    // A release the user skipped stays hidden until something
+                    // newer comes along.
+                    let update = update.filter(|u| {
+                        self.settings
+                            .skipped_app_version
+                            .as_deref()
+                            .is_none_or(|skipped| {
+                                versi_core::is_newer_version(&u.latest_version, skipped)
+                            })
+                    });
+                    if was_manual {
+                        state.settings_state.app_update_check = if update.is_some() {
+                            crate::state::UpdateCheckStatus::UpdateAvailable
+                        } else {
+                            crate::state::UpdateCheckStatus::UpToDate
+                        };
+                    }
+                    state.app_update = update;
+                }
+                Err(e) => {
+                    if was_manual {
+                        state.settings_state.app_update_check =
+                            crate::state::UpdateCheckStatus::Failed;
+                    }
+                    debug!("App update check failed: {}", e);
+                }
             }
         }
     }
@@ -179,9 +256,26 @@ impl Versi {
         result: Result<Option<versi_backend::BackendUpdate>, String>,
     ) {
         if let AppState::Main(state) = &mut self.state {
+            let was_manual = state.settings_state.backend_update_check
+                == crate::state::UpdateCheckStatus::Checking;
             match result {
-                Ok(update) => state.backend_update = update,
-                Err(e) => debug!("Backend update check failed: {}", e),
+                Ok(update) => {
+                    if was_manual {
+                        state.settings_state.backend_update_check = if update.is_some() {
+                            crate::state::UpdateCheckStatus::UpdateAvailable
+                        } else {
+                            crate::state::UpdateCheckStatus::UpToDate
+                        };
+                    }
+                    state.backend_update = update;
+                }
+                Err(e) => {
+                    if was_manual {
+                        state.settings_state.backend_update_check =
+                            crate::state::UpdateCheckStatus::Failed;
+                    }
+                    debug!("Backend update check failed: {}", e);
+                }
             }
         }
     }
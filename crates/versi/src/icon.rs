@@ -51,6 +51,13 @@ pub fn check(size: f32) -> svg::Svg<'static, Theme> {
     themed_icon(include_bytes!("../../../assets/icons/check.svg"), size)
 }
 
+pub fn more_horizontal(size: f32) -> svg::Svg<'static, Theme> {
+    themed_icon(
+        include_bytes!("../../../assets/icons/more-horizontal.svg"),
+        size,
+    )
+}
+
 pub fn chevron_down(size: f32) -> svg::Svg<'static, Theme> {
     themed_icon(
         include_bytes!("../../../assets/icons/chevron-down.svg"),
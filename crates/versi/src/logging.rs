@@ -3,10 +3,33 @@ use simplelog::{ColorChoice, TermLogger, TerminalMode};
 use simplelog::{CombinedLogger, ConfigBuilder, LevelFilter, WriteLogger};
 use std::fs::{File, OpenOptions};
 use std::io::{self, Write};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Mutex;
 use versi_platform::AppPaths;
 
+/// Rotate the log once it grows past this size, keeping one `.old` backup.
+pub const MAX_LOG_SIZE: u64 = 5 * 1024 * 1024;
+
+/// Sibling path where the previous log generation lives after rotation.
+pub fn rotated_log_path(log_path: &Path) -> PathBuf {
+    let mut name = log_path.file_name().unwrap_or_default().to_os_string();
+    name.push(".old");
+    log_path.with_file_name(name)
+}
+
+/// Renames an oversized log to its `.old` sibling so the logger starts
+/// fresh, replacing any previous backup.
+fn rotate_log_if_needed(log_path: &Path) {
+    if let Ok(metadata) = std::fs::metadata(log_path)
+        && metadata.len() > MAX_LOG_SIZE
+    {
+        let backup = rotated_log_path(log_path);
+        // Windows refuses to rename over an existing file.
+        let _ = std::fs::remove_file(&backup);
+        let _ = std::fs::rename(log_path, &backup);
+    }
+}
+
 struct ResilientFileWriter {
     path: PathBuf,
     file: Mutex<Option<File>>,
@@ -65,19 +88,7 @@ pub fn init_logging(debug_enabled: bool) {
     let _ = paths.ensure_dirs();
     let log_path = paths.log_file();
 
-    const MAX_LOG_SIZE: u64 = 5 * 1024 * 1024;
-    if let Ok(metadata) = std::fs::metadata(&log_path)
-        && metadata.len() > MAX_LOG_SIZE
-        && let Ok(contents) = std::fs::read(&log_path)
-    {
-        let half = contents.len() / 2;
-        let keep_from = contents[half..]
-            .iter()
-            .position(|&b| b == b'\n')
-            .map(|p| half + p + 1)
-            .unwrap_or(half);
-        let _ = std::fs::write(&log_path, &contents[keep_from..]);
-    }
+    rotate_log_if_needed(&log_path);
 
     let config = ConfigBuilder::new()
         .set_time_format_rfc3339()
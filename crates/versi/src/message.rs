@@ -1,6 +1,8 @@
 use std::path::PathBuf;
 
-use versi_backend::{BackendUpdate, InstallProgress, InstalledVersion, RemoteVersion};
+use versi_backend::{
+    BackendUpdate, ExecOutput, InstallProgress, InstalledVersion, NodeVersion, RemoteVersion,
+};
 use versi_core::{AppUpdate, ReleaseSchedule};
 use versi_platform::EnvironmentId;
 use versi_shell::ShellType;
@@ -14,9 +16,23 @@ pub enum Message {
     Initialized(InitResult),
 
     EnvironmentSelected(usize),
+    EnvironmentRenameStarted(usize),
+    EnvironmentRenameChanged(String),
+    EnvironmentRenameSubmitted,
     EnvironmentLoaded {
         env_id: EnvironmentId,
         versions: Vec<InstalledVersion>,
+        multishell: Option<NodeVersion>,
+        aliases: Vec<(String, NodeVersion)>,
+    },
+    EnvironmentProbeFailed {
+        env_id: EnvironmentId,
+        reason: String,
+    },
+    BundledNpmLoaded {
+        env_id: EnvironmentId,
+        version: String,
+        npm: Option<String>,
     },
     RefreshEnvironment,
     FocusSearch,
@@ -24,13 +40,25 @@ pub enum Message {
     VersionGroupToggled {
         major: u32,
     },
+    MinorGroupToggled {
+        major: u32,
+        minor: u32,
+    },
     SearchChanged(String),
 
+    OpenBrowseVersions,
+    BrowseQueryChanged(String),
+    BrowseFilterChanged(crate::state::BrowseFilter),
+    BrowseShowMore,
+
     FetchRemoteVersions,
+    CancelRemoteFetch,
     RemoteVersionsFetched(Result<Vec<RemoteVersion>, String>),
     ReleaseScheduleFetched(Result<ReleaseSchedule, String>),
 
     CloseModal,
+    /// Enter pressed: triggers the open confirm modal's primary action.
+    ConfirmModal,
     OpenChangelog(String),
     StartInstall(String),
     InstallProgress {
@@ -44,6 +72,7 @@ pub enum Message {
     },
 
     RequestUninstall(String),
+    ConfirmUninstall,
     UninstallComplete {
         version: String,
         success: bool,
@@ -58,6 +87,22 @@ pub enum Message {
     RequestBulkUninstallMajorExceptLatest {
         major: u32,
     },
+    InstallProjectPin,
+    ProjectPinWritten(Result<String, String>),
+    ScanProjects,
+    ProjectScanCompleted(Vec<String>),
+    /// Scan that precedes the EOL cleanup, with the pinning directories so
+    /// project-required majors can be excluded and explained.
+    EolProjectScanCompleted(Vec<(std::path::PathBuf, String)>),
+    ConfirmInstallFromProjects,
+    ProjectDirInputChanged(String),
+    ProjectDirAdded,
+    ProjectDirRemoved(usize),
+    ManualWslDistroInputChanged(String),
+    ManualWslPathInputChanged(String),
+    ManualWslEnvironmentAdded,
+    ManualWslEnvironmentRemoved(usize),
+
     ConfirmBulkUpdateMajors,
     ConfirmBulkUninstallEOL,
     ConfirmBulkUninstallMajor {
@@ -67,35 +112,101 @@ pub enum Message {
         major: u32,
     },
     CancelBulkOperation,
+    ClearQueue,
 
     SetDefault(String),
     DefaultChanged {
         success: bool,
         error: Option<String>,
+        /// The default before this change, read at execution time so undo
+        /// stays accurate when queued operations are reordered.
+        previous: Option<String>,
     },
+    UndoSetDefault,
+    /// Pin the default to a major line so it floats to the newest installed
+    /// release of that major.
+    PinDefaultMajor {
+        major: u32,
+    },
+    UnpinDefaultMajor,
+    VersionRowDoubleClicked(String),
+    UseVersionComplete(Result<(), String>),
+    OpenRunCommand(String),
+    RunCommandInputChanged(String),
+    RunCommandSubmitted,
+    RunCommandCompleted(Result<ExecOutput, String>),
+    RowDoubleClickActionChanged(crate::settings::RowDoubleClickAction),
+    ChangelogSourceChanged(crate::settings::ChangelogSource),
+    GroupSortChanged(crate::settings::GroupSort),
+    ProgressVerbosityChanged(crate::settings::ProgressVerbosity),
+    FnmDirInputChanged(String),
+    FnmDirSubmitted,
+    CreateFnmDirStructure,
+    ShowAllPatchesToggled(bool),
+    GroupByMinorToggled(bool),
+    GroupByCodenameToggled(bool),
+    AutoExpandDefaultGroupToggled(bool),
+    SkipUninstallConfirmToggled(bool),
+    RememberSearchToggled(bool),
+    RefreshOnShowChanged(crate::settings::RefreshOnShow),
+    FetchOnStartupChanged(crate::settings::FetchOnStartup),
+    InstallTimeoutChanged(u64),
+    IgnoredEolMajorInputChanged(String),
+    IgnoredEolMajorAdded,
+    IgnoredEolMajorRemoved(u32),
 
     ToastDismiss(usize),
 
     NavigateToVersions,
     NavigateToSettings,
     NavigateToAbout,
+    HeaderMenuToggled,
     VersionRowHovered(Option<String>),
     ThemeChanged(crate::settings::ThemeSetting),
+    UseSystemAccentToggled(bool),
     ShellOptionUseOnCdToggled(bool),
     ShellOptionResolveEnginesToggled(bool),
     ShellOptionCorepackEnabledToggled(bool),
     DebugLoggingToggled(bool),
+    ProxyHostChanged(String),
+    ProxyUsernameChanged(String),
+    ProxyPasswordChanged(String),
     CopyToClipboard(String),
+    CopyBugReport,
+    BugReportAssembled(String),
+    ReportIssue,
+    CopyDockerfileLine(String),
+    DockerImageVariantChanged(crate::settings::DockerImageVariant),
     ClearLogFile,
     LogFileCleared,
     RevealLogFile,
-    LogFileStatsLoaded(Option<u64>),
+    RevealBackendDir,
+    RevealVersionDir(String),
+    ViewLogFile,
+    LogFileTailLoaded(String),
+    LogFileStatsLoaded {
+        size: Option<u64>,
+        backup_size: Option<u64>,
+    },
+    CacheSizeLoaded(Option<u64>),
+    RequestClearCache,
+    ConfirmClearCache,
+    CacheCleared,
     ShellSetupChecked(Vec<(ShellType, versi_shell::VerificationResult)>),
     ConfigureShell(ShellType),
-    ShellConfigured(ShellType, Result<(), String>),
-    ShellFlagsUpdated,
+    ShellConfigured(ShellType, Result<std::path::PathBuf, String>),
+    ManualShellPathChanged(String),
+    ManualShellTypeSelected(ShellType),
+    ManualShellConfigure,
+    ManualShellConfigured(ShellType, Result<(), String>),
+    ShellFlagsUpdated(usize),
 
     PreferredBackendChanged(String),
+    MigrateFromNvmRequested,
+    MigrateFromNvmListLoaded(Result<(Vec<String>, Option<String>), String>),
+    MigrateFromNvmSetDefaultToggled(bool),
+    ConfirmMigrateFromNvm,
+    BackendVanished,
 
     OnboardingNext,
     OnboardingBack,
@@ -103,7 +214,11 @@ pub enum Message {
     OnboardingInstallBackend,
     OnboardingBackendInstallResult(Result<(), String>),
     OnboardingConfigureShell(ShellType),
+    OnboardingToggleAllShells,
     OnboardingShellConfigResult(Result<(), String>),
+    OnboardingInstallNode,
+    OnboardingNodeInstallProgress(versi_backend::InstallProgress),
+    OnboardingNodeInstallResult(Result<(), String>),
     OnboardingComplete,
 
     AnimationTick,
@@ -114,11 +229,23 @@ pub enum Message {
 
     TrayEvent(TrayMessage),
     TrayBehaviorChanged(TrayBehavior),
+    CloseActionChanged(crate::settings::CloseAction),
+    ConfirmCloseQuit,
+    ConfirmCloseMinimize,
+    ConfirmQuitAnyway,
+    ConfirmQuitDuringOperationsToggled(bool),
     StartMinimizedToggled(bool),
+    AlwaysOnTopToggled(bool),
     WindowOpened(iced::window::Id),
 
+    CheckForAppUpdate,
+    CheckForBackendUpdate,
     AppUpdateChecked(Result<Option<AppUpdate>, String>),
     OpenAppUpdate,
+    SkipAppUpdate,
+    SelfUpdateToggled(bool),
+    SelfUpdateProgressed(versi_core::SelfUpdateProgress),
+    RestartForUpdate,
     BackendUpdateChecked(Result<Option<BackendUpdate>, String>),
     OpenBackendUpdate,
 
@@ -1,4 +1,4 @@
-use versi_backend::{InstalledVersion, NodeVersion, VersionGroup};
+use versi_backend::{InstalledVersion, NodeVersion, RemoteVersion, VersionGroup};
 use versi_platform::EnvironmentId;
 
 #[derive(Debug)]
@@ -8,8 +8,28 @@ pub struct EnvironmentState {
     pub installed_versions: Vec<InstalledVersion>,
     pub version_groups: Vec<VersionGroup>,
     pub default_version: Option<NodeVersion>,
+    /// The multishell-active version (`fnm current`), when one is set. An
+    /// open terminal may break if this version is uninstalled.
+    pub multishell_version: Option<NodeVersion>,
+    /// Aliases defined in the backend (`default` plus custom ones), shown as
+    /// chips in the header so the alias setup is visible at a glance.
+    pub aliases: Vec<(String, NodeVersion)>,
+    /// Minor subgroups currently expanded when grouping by minor is enabled.
+    /// Kept outside `version_groups` so expansion survives list refreshes.
+    pub expanded_minors: std::collections::HashSet<(u32, u32)>,
+    /// Versions inserted optimistically after a successful install, ahead of
+    /// the authoritative refresh. Cleared when the next full list lands.
+    pub optimistic_installs: Vec<NodeVersion>,
+    /// Bundled npm version per installed Node version, probed lazily on
+    /// first hover (`node -p process.versions.npm`). An entry holding `None`
+    /// means the probe is in flight or came up empty, so it isn't retried.
+    pub bundled_npm: std::collections::HashMap<String, Option<String>>,
     pub backend_name: &'static str,
     pub backend_version: Option<String>,
+    /// Warning from the provider when this environment's backend version is
+    /// known to emit progress output the app can't parse; shown while an
+    /// install runs so a stuck bar doesn't look like a hang.
+    pub progress_note: Option<String>,
     pub loading: bool,
     pub error: Option<String>,
     pub available: bool,
@@ -28,8 +48,14 @@ impl EnvironmentState {
             installed_versions: Vec::new(),
             version_groups: Vec::new(),
             default_version: None,
+            multishell_version: None,
+            aliases: Vec::new(),
+            expanded_minors: std::collections::HashSet::new(),
+            optimistic_installs: Vec::new(),
+            bundled_npm: std::collections::HashMap::new(),
             backend_name,
             backend_version,
+            progress_note: None,
             loading: true,
             error: None,
             available: true,
@@ -44,22 +70,226 @@ impl EnvironmentState {
             installed_versions: Vec::new(),
             version_groups: Vec::new(),
             default_version: None,
+            multishell_version: None,
+            aliases: Vec::new(),
+            expanded_minors: std::collections::HashSet::new(),
+            optimistic_installs: Vec::new(),
+            bundled_npm: std::collections::HashMap::new(),
             backend_name,
             backend_version: None,
+            progress_note: None,
             loading: false,
             error: Some(reason.to_string()),
             available: false,
         }
     }
 
-    pub fn update_versions(&mut self, versions: Vec<InstalledVersion>) {
+    /// Replaces the installed version list and reconciles the default and
+    /// per-major expansion state.
+    ///
+    /// Returns `true` if the previously known default version disappeared
+    /// from the installed list without a replacement — typically fnm's
+    /// `default` alias pointing at a version that was deleted manually.
+    /// The phantom default is cleared so the UI doesn't mark a missing
+    /// version as default.
+    pub fn update_versions(
+        &mut self,
+        versions: Vec<InstalledVersion>,
+        group_by_codename: bool,
+        expand_default_group: bool,
+    ) -> bool {
+        let previous_default = self.default_version.take();
         self.default_version = versions
             .iter()
             .find(|v| v.is_default)
             .map(|v| v.version.clone());
-        self.version_groups = VersionGroup::from_versions(versions.clone());
+        let default_broken = self.default_version.is_none()
+            && previous_default.is_some_and(|prev| !versions.iter().any(|v| v.version == prev));
+        let previous_expansion: std::collections::HashMap<u32, bool> = self
+            .version_groups
+            .iter()
+            .map(|g| (g.major, g.is_expanded))
+            .collect();
+        self.version_groups = if group_by_codename {
+            VersionGroup::from_versions_by_codename(versions.clone())
+        } else {
+            VersionGroup::from_versions(versions.clone())
+        };
+        // Rebuilt groups start expanded; carry the previous per-major state
+        // over so a refresh doesn't undo manual collapsing. Majors without
+        // prior state stay open, and with `expand_default_group` the group
+        // holding the default opens for them regardless of anything (like
+        // the EOL fold) collapsing it later.
+        for group in &mut self.version_groups {
+            if let Some(prev) = previous_expansion.get(&group.major) {
+                group.is_expanded = *prev;
+            } else if expand_default_group
+                && let Some(default) = &self.default_version
+                && group.versions.iter().any(|v| &v.version == default)
+            {
+                group.is_expanded = true;
+            }
+        }
         self.installed_versions = versions;
+        self.optimistic_installs.clear();
         self.loading = false;
         self.error = None;
+        default_broken
+    }
+
+    /// How many installed major lines have a newer remote release than their
+    /// newest installed version. Drives the updates banner and the tray
+    /// tooltip.
+    pub fn majors_with_updates(&self, remote: &[RemoteVersion]) -> usize {
+        let mut latest_by_major: std::collections::HashMap<u32, &NodeVersion> =
+            std::collections::HashMap::new();
+        for v in remote {
+            latest_by_major
+                .entry(v.version.major)
+                .and_modify(|existing| {
+                    if &v.version > *existing {
+                        *existing = &v.version;
+                    }
+                })
+                .or_insert(&v.version);
+        }
+
+        self.version_groups
+            .iter()
+            .filter(|group| {
+                let installed_latest = group.versions.iter().map(|v| &v.version).max();
+                latest_by_major.get(&group.major).is_some_and(|latest| {
+                    installed_latest.is_some_and(|installed| *latest > installed)
+                })
+            })
+            .count()
+    }
+
+    /// Optimistically inserts a just-installed version so it appears without
+    /// waiting for the re-list; the next [`Self::update_versions`] replaces
+    /// the whole list and so reconciles it. Returns `false` when the version
+    /// was already present.
+    pub fn insert_optimistic(
+        &mut self,
+        version: InstalledVersion,
+        group_by_codename: bool,
+    ) -> bool {
+        if self
+            .installed_versions
+            .iter()
+            .any(|v| v.version == version.version)
+        {
+            return false;
+        }
+        self.optimistic_installs.push(version.version.clone());
+        self.installed_versions.push(version);
+        self.version_groups = if group_by_codename {
+            VersionGroup::from_versions_by_codename(self.installed_versions.clone())
+        } else {
+            VersionGroup::from_versions(self.installed_versions.clone())
+        };
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn installed(version: &str, is_default: bool) -> InstalledVersion {
+        InstalledVersion {
+            version: version.parse().unwrap(),
+            is_default,
+            lts_codename: None,
+            install_date: None,
+            disk_size: None,
+            arch: None,
+        }
+    }
+
+    #[test]
+    fn test_update_versions_detects_broken_default() {
+        let mut env = EnvironmentState::new(EnvironmentId::Native, "fnm", None);
+        assert!(!env.update_versions(
+            vec![installed("v20.11.0", true), installed("v18.19.1", false)],
+            false,
+            true,
+        ));
+        assert_eq!(env.default_version, Some("v20.11.0".parse().unwrap()));
+
+        // The default alias target was deleted manually; the refreshed list
+        // no longer contains it and nothing else is marked default.
+        assert!(env.update_versions(vec![installed("v18.19.1", false)], false, true));
+        assert_eq!(env.default_version, None);
+    }
+
+    #[test]
+    fn test_insert_optimistic_adds_and_regroups() {
+        let mut env = EnvironmentState::new(EnvironmentId::Native, "fnm", None);
+        env.update_versions(vec![installed("v20.11.0", true)], false, true);
+
+        assert!(env.insert_optimistic(installed("v22.1.0", false), false));
+        assert_eq!(env.installed_versions.len(), 2);
+        assert_eq!(env.version_groups.len(), 2);
+        assert_eq!(env.optimistic_installs, vec!["v22.1.0".parse().unwrap()]);
+
+        // Already present: nothing to insert.
+        assert!(!env.insert_optimistic(installed("v20.11.0", false), false));
+        assert_eq!(env.installed_versions.len(), 2);
+    }
+
+    #[test]
+    fn test_update_versions_clears_optimistic_installs() {
+        let mut env = EnvironmentState::new(EnvironmentId::Native, "fnm", None);
+        env.insert_optimistic(installed("v22.1.0", false), false);
+
+        env.update_versions(vec![installed("v22.1.0", false)], false, true);
+        assert!(env.optimistic_installs.is_empty());
+    }
+
+    #[test]
+    fn test_update_versions_preserves_expansion_for_known_majors() {
+        let mut env = EnvironmentState::new(EnvironmentId::Native, "fnm", None);
+        env.update_versions(
+            vec![installed("v20.11.0", true), installed("v18.19.1", false)],
+            false,
+            true,
+        );
+        env.version_groups
+            .iter_mut()
+            .find(|g| g.major == 18)
+            .unwrap()
+            .is_expanded = false;
+
+        // Refresh: the manual collapse carries over; a newly appearing major
+        // starts expanded.
+        env.update_versions(
+            vec![
+                installed("v22.1.0", false),
+                installed("v20.11.0", true),
+                installed("v18.19.1", false),
+            ],
+            false,
+            true,
+        );
+        let expanded = |major: u32| {
+            env.version_groups
+                .iter()
+                .find(|g| g.major == major)
+                .unwrap()
+                .is_expanded
+        };
+        assert!(!expanded(18));
+        assert!(expanded(20));
+        assert!(expanded(22));
+    }
+
+    #[test]
+    fn test_update_versions_default_removed_with_replacement() {
+        let mut env = EnvironmentState::new(EnvironmentId::Native, "fnm", None);
+        env.update_versions(vec![installed("v20.11.0", true)], false, true);
+
+        assert!(!env.update_versions(vec![installed("v18.19.1", true)], false, true));
+        assert_eq!(env.default_version, Some("v18.19.1".parse().unwrap()));
     }
 }
@@ -13,15 +13,35 @@ pub struct MainState {
     pub toasts: Vec<Toast>,
     pub modal: Option<Modal>,
     pub search_query: String,
+    pub range_match: Option<RemoteVersion>,
     pub backend: Box<dyn VersionManager>,
     pub app_update: Option<AppUpdate>,
     pub backend_update: Option<BackendUpdate>,
     pub view: MainViewKind,
     pub settings_state: SettingsModalState,
     pub hovered_version: Option<String>,
+    /// Environment tab currently being renamed: (index, draft label).
+    pub renaming_environment: Option<(usize, String)>,
     pub backend_name: &'static str,
     pub detected_backends: Vec<&'static str>,
     pub refresh_rotation: f32,
+    /// Whether the header's overflow menu is expanded; only shown below the
+    /// compact-width breakpoint, where the nav icons collapse behind it.
+    pub header_menu_open: bool,
+    /// After a successful set-default: `(new, previous)`, with the previous
+    /// default read at execution time so undo restores the right version.
+    pub undo_default: Option<(String, String)>,
+    /// Pin file found in the directory Versi was launched from, if any.
+    pub project_pin: Option<ProjectPin>,
+    /// Version installing on behalf of [`Self::project_pin`]; when it lands,
+    /// the pin file is rewritten with this concrete version.
+    pub pin_install: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ProjectPin {
+    pub file: std::path::PathBuf,
+    pub raw: String,
 }
 
 impl std::fmt::Debug for MainState {
@@ -57,15 +77,21 @@ impl MainState {
             toasts: Vec::new(),
             modal: None,
             search_query: String::new(),
+            range_match: None,
             backend,
             app_update: None,
             backend_update: None,
             view: MainViewKind::default(),
             settings_state: SettingsModalState::new(),
             hovered_version: None,
+            renaming_environment: None,
             backend_name,
             detected_backends: Vec::new(),
             refresh_rotation: 0.0,
+            header_menu_open: false,
+            undo_default: None,
+            project_pin: None,
+            pin_install: None,
         }
     }
 
@@ -10,6 +10,20 @@ pub struct OnboardingState {
     pub detected_shells: Vec<ShellConfigStatus>,
     pub available_backends: Vec<BackendOption>,
     pub selected_backend: Option<String>,
+    /// Set when a previously working backend disappeared at runtime, so the
+    /// welcome step can explain why the user is back in onboarding.
+    pub vanished_backend: Option<&'static str>,
+    /// The user's login shell (`$SHELL`), when detected. The shell step
+    /// leads with one big configure button for it and tucks the rest behind
+    /// an expander.
+    pub login_shell: Option<ShellType>,
+    /// Whether the shell step's "Other shells" expander is open.
+    pub show_all_shells: bool,
+    /// One-click Node install on the final step: whether it is running, the
+    /// latest progress update for inline display, and a failure message.
+    pub node_installing: bool,
+    pub node_install_progress: Option<versi_backend::InstallProgress>,
+    pub node_install_error: Option<String>,
 }
 
 impl OnboardingState {
@@ -21,6 +35,12 @@ impl OnboardingState {
             detected_shells: Vec::new(),
             available_backends: Vec::new(),
             selected_backend: None,
+            vanished_backend: None,
+            login_shell: None,
+            show_all_shells: false,
+            node_installing: false,
+            node_install_progress: None,
+            node_install_error: None,
         }
     }
 }
@@ -31,6 +51,7 @@ pub enum OnboardingStep {
     SelectBackend,
     InstallBackend,
     ConfigureShell,
+    InstallNode,
 }
 
 #[derive(Debug, Clone)]
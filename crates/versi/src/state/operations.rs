@@ -1,21 +1,42 @@
 use std::collections::VecDeque;
 
-use versi_backend::InstallProgress;
+use versi_backend::{ExecOutput, InstallProgress};
+use versi_platform::EnvironmentId;
 
 #[derive(Debug, Clone)]
 pub enum Operation {
     Install {
         version: String,
         progress: InstallProgress,
+        env_id: EnvironmentId,
     },
     Uninstall {
         version: String,
+        env_id: EnvironmentId,
     },
     SetDefault {
         version: String,
+        env_id: EnvironmentId,
     },
 }
 
+impl Operation {
+    /// Human-readable label, e.g. "Install Node v20.11.0 (Ubuntu)". The
+    /// environment name is only appended when given, so single-environment
+    /// setups keep the short form.
+    pub fn description(&self, env_name: Option<&str>) -> String {
+        let base = match self {
+            Self::Install { version, .. } => format!("Install Node {}", version),
+            Self::Uninstall { version, .. } => format!("Uninstall Node {}", version),
+            Self::SetDefault { version, .. } => format!("Set default to Node {}", version),
+        };
+        match env_name {
+            Some(name) => format!("{} ({})", base, name),
+            None => base,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum OperationRequest {
     Install { version: String },
@@ -36,6 +57,23 @@ impl OperationRequest {
 #[derive(Debug, Clone)]
 pub struct QueuedOperation {
     pub request: OperationRequest,
+    pub env_id: EnvironmentId,
+}
+
+impl QueuedOperation {
+    /// Same shape as [`Operation::description`], for items still waiting in
+    /// the queue.
+    pub fn description(&self, env_name: Option<&str>) -> String {
+        let base = match &self.request {
+            OperationRequest::Install { version } => format!("Install Node {}", version),
+            OperationRequest::Uninstall { version } => format!("Uninstall Node {}", version),
+            OperationRequest::SetDefault { version } => format!("Set default to Node {}", version),
+        };
+        match env_name {
+            Some(name) => format!("{} ({})", base, name),
+            None => base,
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -78,6 +116,11 @@ impl OperationQueue {
         !self.active_installs.is_empty() || self.exclusive_op.is_some()
     }
 
+    /// Anything running or queued at all; used by the quit guard.
+    pub fn has_operations(&self) -> bool {
+        !self.active_installs.is_empty() || self.exclusive_op.is_some() || !self.pending.is_empty()
+    }
+
     pub fn has_pending_for_version(&self, version: &str) -> bool {
         self.pending
             .iter()
@@ -96,8 +139,8 @@ impl OperationQueue {
             .as_ref()
             .map(|op| match op {
                 Operation::Install { version: v, .. } => v == version,
-                Operation::Uninstall { version: v } => v == version,
-                Operation::SetDefault { version: v } => v == version,
+                Operation::Uninstall { version: v, .. } => v == version,
+                Operation::SetDefault { version: v, .. } => v == version,
             })
             .unwrap_or(false)
     }
@@ -112,11 +155,17 @@ impl OperationQueue {
         }
         self.exclusive_op.as_ref().filter(|op| match op {
             Operation::Install { version: v, .. } => v == version,
-            Operation::Uninstall { version: v } => v == version,
-            Operation::SetDefault { version: v } => v == version,
+            Operation::Uninstall { version: v, .. } => v == version,
+            Operation::SetDefault { version: v, .. } => v == version,
         })
     }
 
+    /// Drops every queued operation. Operations already running are not
+    /// affected.
+    pub fn clear_pending(&mut self) {
+        self.pending.clear();
+    }
+
     pub fn remove_completed_install(&mut self, version: &str) {
         self.active_installs.retain(|op| match op {
             Operation::Install { version: v, .. } => v != version,
@@ -138,13 +187,62 @@ impl OperationQueue {
     }
 }
 
+/// Schedule-aware filter in the Browse Versions modal.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BrowseFilter {
+    All,
+    Lts,
+    Current,
+    Eol,
+}
+
 #[derive(Debug, Clone)]
 pub enum Modal {
+    LogViewer {
+        content: String,
+    },
+    BrowseVersions {
+        query: String,
+        filter: BrowseFilter,
+        /// How many matching versions are rendered; grown by "Show More".
+        limit: usize,
+    },
+    RunCommand {
+        version: String,
+        input: String,
+        running: bool,
+        output: Option<Result<ExecOutput, String>>,
+    },
+    ConfirmClose,
+    /// Quit was requested while operations were running or queued; quitting
+    /// now would kill them mid-way.
+    ConfirmQuitDuringOperations,
+    ConfirmUninstall {
+        version: String,
+        is_default: bool,
+        is_last: bool,
+        is_in_use: bool,
+        /// Where the version lives on disk, when the data directory is known.
+        install_path: Option<std::path::PathBuf>,
+        disk_size: Option<u64>,
+    },
     ConfirmBulkUpdateMajors {
         versions: Vec<(String, String)>,
     },
+    ConfirmInstallFromProjects {
+        versions: Vec<String>,
+    },
     ConfirmBulkUninstallEOL {
         versions: Vec<String>,
+        /// EOL majors excluded because a scanned project pins them:
+        /// (major label like "18.x", project directory).
+        kept: Vec<(String, String)>,
+    },
+    ConfirmMigrateFromNvm {
+        versions: Vec<String>,
+        /// nvm's default version, offered as the fnm default too.
+        default_version: Option<String>,
+        set_default: bool,
     },
     ConfirmBulkUninstallMajor {
         major: u32,
@@ -155,4 +253,15 @@ pub enum Modal {
         versions: Vec<String>,
         keeping: String,
     },
+    ConfirmClearCache {
+        /// Measured cache size at the time the modal opened.
+        size: u64,
+    },
+    /// In-place app update in flight; closes only on failure or restart.
+    AppUpdateProgress {
+        version: String,
+        /// Fallback for the failure state's "open release page" action.
+        release_url: String,
+        progress: versi_core::SelfUpdateProgress,
+    },
 }
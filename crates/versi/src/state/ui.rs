@@ -26,6 +26,35 @@ pub struct SettingsModalState {
     pub shell_statuses: Vec<ShellSetupStatus>,
     pub checking_shells: bool,
     pub log_file_size: Option<u64>,
+    /// Size of the rotated `.old` log backup, when one exists.
+    pub log_backup_size: Option<u64>,
+    /// Size of the backend's download cache; `None` until measured (or when
+    /// the backend has no known data dir).
+    pub cache_size: Option<u64>,
+    pub app_update_check: UpdateCheckStatus,
+    pub backend_update_check: UpdateCheckStatus,
+    pub project_dir_input: String,
+    /// Drafts for the manual WSL environment form (distro name and backend
+    /// path).
+    pub manual_wsl_distro_input: String,
+    pub manual_wsl_path_input: String,
+    /// Draft major number typed into the "never treat as EOL" list.
+    pub eol_pin_input: String,
+    /// Draft for the custom `FNM_DIR` override, plus the validation error
+    /// from the last apply attempt (shown inline under the input).
+    pub fnm_dir_input: String,
+    pub fnm_dir_error: Option<String>,
+    /// How many configured shells the last shell-option toggle touched.
+    pub shell_flags_updated: Option<usize>,
+    /// Shown after a successful shell configuration: how to apply the edit
+    /// in already-open terminals (restart or a shell-appropriate `source`).
+    pub restart_hint: Option<String>,
+    /// Manual shell setup for when auto-detection finds nothing: the config
+    /// file path typed by the user, the shell type it belongs to, and the
+    /// outcome of the last configure attempt.
+    pub manual_shell_path: String,
+    pub manual_shell_type: versi_shell::ShellType,
+    pub manual_shell_result: Option<Result<(), String>>,
 }
 
 impl SettingsModalState {
@@ -34,10 +63,37 @@ impl SettingsModalState {
             shell_statuses: Vec::new(),
             checking_shells: false,
             log_file_size: None,
+            log_backup_size: None,
+            cache_size: None,
+            app_update_check: UpdateCheckStatus::Idle,
+            backend_update_check: UpdateCheckStatus::Idle,
+            project_dir_input: String::new(),
+            manual_wsl_distro_input: String::new(),
+            manual_wsl_path_input: String::new(),
+            eol_pin_input: String::new(),
+            fnm_dir_input: String::new(),
+            fnm_dir_error: None,
+            shell_flags_updated: None,
+            restart_hint: None,
+            manual_shell_path: String::new(),
+            manual_shell_type: versi_shell::ShellType::Bash,
+            manual_shell_result: None,
         }
     }
 }
 
+/// Progress of a manually triggered update check in settings. Background
+/// checks do not touch this; it only reflects the "Check Now" buttons.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum UpdateCheckStatus {
+    #[default]
+    Idle,
+    Checking,
+    UpToDate,
+    UpdateAvailable,
+    Failed,
+}
+
 #[derive(Debug, Clone)]
 pub struct ShellSetupStatus {
     pub shell_type: versi_shell::ShellType,
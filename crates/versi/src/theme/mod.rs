@@ -18,12 +18,17 @@ pub mod tahoe {
 }
 
 pub fn light_theme() -> Theme {
+    light_theme_with(None)
+}
+
+/// Light theme with an optional accent replacing the default blue primary.
+pub fn light_theme_with(accent: Option<iced::Color>) -> Theme {
     Theme::custom(
         "Versi Light".to_string(),
         Palette {
             background: color!(0xf5f5f7),
             text: color!(0x1d1d1f),
-            primary: color!(0x007aff),
+            primary: accent.unwrap_or(color!(0x007aff)),
             success: color!(0x34c759),
             danger: color!(0xff3b30),
             warning: color!(0xff9500),
@@ -32,12 +37,17 @@ pub fn light_theme() -> Theme {
 }
 
 pub fn dark_theme() -> Theme {
+    dark_theme_with(None)
+}
+
+/// Dark theme with an optional accent replacing the default blue primary.
+pub fn dark_theme_with(accent: Option<iced::Color>) -> Theme {
     Theme::custom(
         "Versi Dark".to_string(),
         Palette {
             background: color!(0x1c1c1e),
             text: color!(0xf5f5f7),
-            primary: color!(0x0a84ff),
+            primary: accent.unwrap_or(color!(0x0a84ff)),
             success: color!(0x30d158),
             danger: color!(0xff453a),
             warning: color!(0xff9f0a),
@@ -56,3 +66,86 @@ pub fn get_system_theme() -> Theme {
 pub fn is_system_dark() -> bool {
     matches!(dark_light::detect(), Ok(dark_light::Mode::Dark))
 }
+
+static ACCENT_CACHE: std::sync::Mutex<Option<(bool, Option<iced::Color>)>> =
+    std::sync::Mutex::new(None);
+
+/// The OS accent color on macOS/Windows, cached per light/dark mode so a
+/// system theme switch re-reads it. `None` where the platform exposes no
+/// accent or the read fails, letting callers keep the default blue.
+pub fn system_accent(is_dark: bool) -> Option<iced::Color> {
+    let mut cache = ACCENT_CACHE.lock().unwrap();
+    if let Some((cached_dark, color)) = *cache
+        && cached_dark == is_dark
+    {
+        return color;
+    }
+    let color = read_system_accent(is_dark);
+    *cache = Some((is_dark, color));
+    color
+}
+
+#[cfg(target_os = "macos")]
+fn read_system_accent(is_dark: bool) -> Option<iced::Color> {
+    // `defaults` exits non-zero when the key is unset (the default blue).
+    let output = std::process::Command::new("defaults")
+        .args(["read", "-g", "AppleAccentColor"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let index: i32 = String::from_utf8_lossy(&output.stdout)
+        .trim()
+        .parse()
+        .ok()?;
+    // Apple's system colors, which differ slightly between appearances.
+    let (light, dark): (u32, u32) = match index {
+        -1 => (0x8e8e93, 0x98989d), // graphite
+        0 => (0xff3b30, 0xff453a),  // red
+        1 => (0xff9500, 0xff9f0a),  // orange
+        2 => (0xffcc00, 0xffd60a),  // yellow
+        3 => (0x34c759, 0x30d158),  // green
+        4 => (0x007aff, 0x0a84ff),  // blue
+        5 => (0xaf52de, 0xbf5af2),  // purple
+        6 => (0xff2d55, 0xff375f),  // pink
+        _ => return None,
+    };
+    let rgb = if is_dark { dark } else { light };
+    Some(iced::Color::from_rgb8(
+        (rgb >> 16) as u8,
+        (rgb >> 8) as u8,
+        rgb as u8,
+    ))
+}
+
+#[cfg(windows)]
+fn read_system_accent(_is_dark: bool) -> Option<iced::Color> {
+    // DWM stores the accent as an ABGR dword; the same value serves both
+    // appearances on Windows.
+    let output = std::process::Command::new("reg")
+        .args([
+            "query",
+            r"HKCU\Software\Microsoft\Windows\DWM",
+            "/v",
+            "AccentColor",
+        ])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let raw = stdout.split_whitespace().find(|t| t.starts_with("0x"))?;
+    let abgr = u32::from_str_radix(raw.trim_start_matches("0x"), 16).ok()?;
+    Some(iced::Color::from_rgb8(
+        abgr as u8,
+        (abgr >> 8) as u8,
+        (abgr >> 16) as u8,
+    ))
+}
+
+#[cfg(not(any(target_os = "macos", windows)))]
+fn read_system_accent(_is_dark: bool) -> Option<iced::Color> {
+    None
+}
@@ -37,6 +37,88 @@ pub fn badge_lts(theme: &Theme) -> container::Style {
     }
 }
 
+pub fn badge_in_use(_theme: &Theme) -> container::Style {
+    let in_use_color = Color::from_rgb8(255, 149, 0);
+
+    container::Style {
+        background: Some(Background::Color(Color {
+            a: 0.15,
+            ..in_use_color
+        })),
+        text_color: Some(in_use_color),
+        border: Border {
+            radius: crate::theme::tahoe::RADIUS_SM.into(),
+            width: 0.0,
+            color: Color::TRANSPARENT,
+        },
+        ..Default::default()
+    }
+}
+
+pub fn badge_arch(_theme: &Theme) -> container::Style {
+    let arch_color = Color::from_rgb8(142, 142, 147);
+
+    container::Style {
+        background: Some(Background::Color(Color {
+            a: 0.15,
+            ..arch_color
+        })),
+        text_color: Some(arch_color),
+        border: Border {
+            radius: crate::theme::tahoe::RADIUS_SM.into(),
+            width: 0.0,
+            color: Color::TRANSPARENT,
+        },
+        ..Default::default()
+    }
+}
+
+pub fn badge_unofficial(_theme: &Theme) -> container::Style {
+    let unofficial_color = Color::from_rgb8(175, 82, 222);
+
+    container::Style {
+        background: Some(Background::Color(Color {
+            a: 0.15,
+            ..unofficial_color
+        })),
+        text_color: Some(unofficial_color),
+        border: Border {
+            radius: crate::theme::tahoe::RADIUS_SM.into(),
+            width: 0.0,
+            color: Color::TRANSPARENT,
+        },
+        ..Default::default()
+    }
+}
+
+fn status_dot(color: Color) -> container::Style {
+    container::Style {
+        background: Some(Background::Color(color)),
+        border: Border {
+            radius: 4.0.into(),
+            width: 0.0,
+            color: Color::TRANSPARENT,
+        },
+        ..Default::default()
+    }
+}
+
+pub fn status_dot_online(theme: &Theme) -> container::Style {
+    status_dot(theme.palette().success)
+}
+
+pub fn status_dot_fetching(theme: &Theme) -> container::Style {
+    status_dot(theme.palette().primary)
+}
+
+pub fn status_dot_stale(_theme: &Theme) -> container::Style {
+    status_dot(Color::from_rgb8(255, 149, 0))
+}
+
+pub fn status_dot_offline(theme: &Theme) -> container::Style {
+    status_dot(theme.palette().danger)
+}
+
 pub fn badge_eol(_theme: &Theme) -> container::Style {
     let eol_color = Color::from_rgb8(255, 149, 0);
 
@@ -6,12 +6,17 @@ use tray_icon::{Icon, TrayIcon, TrayIconBuilder};
 
 use crate::message::Message;
 use crate::settings::TrayBehavior;
-use crate::state::EnvironmentState;
+use crate::state::{EnvironmentState, Operation, OperationQueue};
 
 thread_local! {
     static TRAY_ICON: RefCell<Option<TrayIcon>> = const { RefCell::new(None) };
 }
 
+/// How many set-default entries each environment contributes to the tray
+/// menu. Many WSL distros with many versions each would otherwise make the
+/// menu enormous; the rest stays reachable through the main window.
+const TRAY_MAX_VERSIONS_PER_ENV: usize = 5;
+
 #[derive(Debug, Clone)]
 pub enum TrayMessage {
     ShowWindow,
@@ -23,11 +28,15 @@ pub enum TrayMessage {
 
 pub struct TrayMenuData {
     pub environments: Vec<EnvironmentData>,
+    pub active_installs: Vec<InstallData>,
+    pub pending: Vec<String>,
 }
 
 pub struct EnvironmentData {
     pub name: String,
     pub versions: Vec<VersionData>,
+    /// How many installed versions were left out by the per-environment cap.
+    pub omitted: usize,
 }
 
 pub struct VersionData {
@@ -35,27 +44,96 @@ pub struct VersionData {
     pub is_default: bool,
 }
 
+pub struct InstallData {
+    pub version: String,
+    pub description: String,
+    pub percent: Option<f32>,
+}
+
 impl TrayMenuData {
-    pub fn from_environments(environments: &[EnvironmentState]) -> Self {
+    pub fn from_environments(
+        environments: &[EnvironmentState],
+        operation_queue: &OperationQueue,
+    ) -> Self {
+        // Environment names only disambiguate when there is more than one
+        // environment, so single-environment setups keep the short labels.
+        let multi_env = environments.len() > 1;
+        let env_name = |env_id: &versi_platform::EnvironmentId| {
+            multi_env
+                .then(|| environments.iter().find(|e| &e.id == env_id))
+                .flatten()
+                .map(|e| e.name.as_str())
+        };
+
         Self {
+            active_installs: operation_queue
+                .active_installs
+                .iter()
+                .filter_map(|op| match op {
+                    Operation::Install {
+                        version,
+                        progress,
+                        env_id,
+                    } => Some(InstallData {
+                        version: version.clone(),
+                        description: op.description(env_name(env_id)),
+                        percent: progress.percent,
+                    }),
+                    _ => None,
+                })
+                .collect(),
+            pending: operation_queue
+                .pending
+                .iter()
+                .map(|queued| queued.description(env_name(&queued.env_id)))
+                .collect(),
             environments: environments
                 .iter()
-                .map(|env| EnvironmentData {
-                    name: env.name.clone(),
-                    versions: env
-                        .installed_versions
-                        .iter()
-                        .map(|v| VersionData {
-                            version: v.version.to_string(),
-                            is_default: v.is_default,
-                        })
-                        .collect(),
+                .map(|env| {
+                    let (versions, omitted) =
+                        cap_tray_versions(&env.installed_versions, TRAY_MAX_VERSIONS_PER_ENV);
+                    EnvironmentData {
+                        name: env.name.clone(),
+                        versions,
+                        omitted,
+                    }
                 })
                 .collect(),
         }
     }
 }
 
+/// Picks which of an environment's versions appear in the tray: the newest
+/// `limit`, plus the default even when it is older than those. Also returns
+/// how many versions were left out.
+fn cap_tray_versions(
+    installed: &[versi_backend::InstalledVersion],
+    limit: usize,
+) -> (Vec<VersionData>, usize) {
+    let mut sorted: Vec<&versi_backend::InstalledVersion> = installed.iter().collect();
+    sorted.sort_by(|a, b| b.version.cmp(&a.version));
+
+    let mut picked: Vec<&versi_backend::InstalledVersion> =
+        sorted.iter().take(limit).copied().collect();
+
+    if let Some(default) = sorted.iter().find(|v| v.is_default)
+        && !picked.iter().any(|v| v.version == default.version)
+        && let Some(last) = picked.last_mut()
+    {
+        *last = default;
+    }
+
+    let omitted = installed.len().saturating_sub(picked.len());
+    let versions = picked
+        .into_iter()
+        .map(|v| VersionData {
+            version: v.version.to_string(),
+            is_default: v.is_default,
+        })
+        .collect();
+    (versions, omitted)
+}
+
 pub fn init_tray(behavior: &TrayBehavior) -> Result<(), Box<dyn std::error::Error>> {
     if *behavior == TrayBehavior::Disabled {
         return Ok(());
@@ -64,6 +142,8 @@ pub fn init_tray(behavior: &TrayBehavior) -> Result<(), Box<dyn std::error::Erro
     let icon = load_icon()?;
     let menu = build_menu(&TrayMenuData {
         environments: vec![],
+        active_installs: vec![],
+        pending: vec![],
     });
 
     let tray_icon = TrayIconBuilder::new()
@@ -101,6 +181,33 @@ fn build_menu(data: &TrayMenuData) -> Menu {
     let menu = Menu::new();
     let show_multiple_envs = data.environments.len() > 1;
 
+    for install in &data.active_installs {
+        let label = match install.percent {
+            Some(percent) => format!("{} ({:.0}%)", install.description, percent),
+            None => format!("{}...", install.description),
+        };
+
+        let _ = menu.append(&MenuItem::with_id(
+            MenuId::new(format!("install_progress:{}", install.version)),
+            label,
+            false,
+            None,
+        ));
+    }
+
+    for (idx, description) in data.pending.iter().enumerate() {
+        let _ = menu.append(&MenuItem::with_id(
+            MenuId::new(format!("queued:{}", idx)),
+            format!("Queued: {}", description),
+            false,
+            None,
+        ));
+    }
+
+    if !data.active_installs.is_empty() || !data.pending.is_empty() {
+        let _ = menu.append(&PredefinedMenuItem::separator());
+    }
+
     for (env_idx, env) in data.environments.iter().enumerate() {
         if show_multiple_envs {
             let _ = menu.append(&MenuItem::with_id(
@@ -126,6 +233,15 @@ fn build_menu(data: &TrayMenuData) -> Menu {
             ));
         }
 
+        if env.omitted > 0 {
+            let _ = menu.append(&MenuItem::with_id(
+                MenuId::new(format!("more:{}", env_idx)),
+                format!("{} more…", env.omitted),
+                true,
+                None,
+            ));
+        }
+
         if show_multiple_envs && env_idx < data.environments.len() - 1 {
             let _ = menu.append(&PredefinedMenuItem::separator());
         }
@@ -168,12 +284,55 @@ pub fn update_menu(data: &TrayMenuData) {
     });
 }
 
+pub fn set_tooltip(tooltip: &str) {
+    TRAY_ICON.with(|cell| {
+        if let Some(tray) = cell.borrow().as_ref() {
+            let _ = tray.set_tooltip(Some(tooltip));
+        }
+    });
+}
+
+/// Tooltip summarizing the active environment's health — the default version
+/// and how many installed majors have updates available, e.g.
+/// "Versi — Node 20.11.1 default, 2 updates available". Falls back to the
+/// plain app name when nothing is known yet.
+pub fn health_tooltip(env: &EnvironmentState, remote: &[versi_backend::RemoteVersion]) -> String {
+    let mut tooltip = String::from("Versi");
+
+    if let Some(default) = &env.default_version {
+        let bare = default.to_string();
+        tooltip.push_str(&format!(
+            " \u{2014} Node {} default",
+            bare.trim_start_matches('v')
+        ));
+    }
+
+    let updates = env.majors_with_updates(remote);
+    if updates > 0 {
+        let sep = if env.default_version.is_some() {
+            ", "
+        } else {
+            " \u{2014} "
+        };
+        tooltip.push_str(&format!(
+            "{}{} update{} available",
+            sep,
+            updates,
+            if updates == 1 { "" } else { "s" }
+        ));
+    }
+
+    tooltip
+}
+
 fn parse_menu_event(id: &str) -> Option<TrayMessage> {
     match id {
         "show_window" => Some(TrayMessage::ShowWindow),
         "open_settings" => Some(TrayMessage::OpenSettings),
         "open_about" => Some(TrayMessage::OpenAbout),
         "quit" => Some(TrayMessage::Quit),
+        // The capped entries are only reachable in the full window.
+        s if s.starts_with("more:") => Some(TrayMessage::ShowWindow),
         s if s.starts_with("set:") => {
             let parts: Vec<&str> = s.splitn(3, ':').collect();
             if parts.len() == 3 {
@@ -205,3 +364,95 @@ pub fn tray_subscription() -> Subscription<Message> {
         })
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use versi_backend::InstalledVersion;
+
+    fn installed(version: &str, is_default: bool) -> InstalledVersion {
+        InstalledVersion {
+            version: version.parse().unwrap(),
+            is_default,
+            lts_codename: None,
+            install_date: None,
+            disk_size: None,
+            arch: None,
+        }
+    }
+
+    #[test]
+    fn test_cap_tray_versions_under_limit() {
+        let versions = vec![installed("v20.11.0", true), installed("v18.19.1", false)];
+        let (picked, omitted) = cap_tray_versions(&versions, 5);
+        assert_eq!(picked.len(), 2);
+        assert_eq!(omitted, 0);
+    }
+
+    #[test]
+    fn test_cap_tray_versions_keeps_newest() {
+        let versions = vec![
+            installed("v16.20.2", false),
+            installed("v22.1.0", true),
+            installed("v18.19.1", false),
+            installed("v20.11.0", false),
+        ];
+        let (picked, omitted) = cap_tray_versions(&versions, 2);
+        assert_eq!(omitted, 2);
+        assert_eq!(picked[0].version, "v22.1.0");
+        assert_eq!(picked[1].version, "v20.11.0");
+    }
+
+    fn remote(version: &str) -> versi_backend::RemoteVersion {
+        versi_backend::RemoteVersion {
+            version: version.parse().unwrap(),
+            lts_codename: None,
+            is_latest: false,
+        }
+    }
+
+    #[test]
+    fn test_health_tooltip_summarizes_default_and_updates() {
+        let mut env = EnvironmentState::new(versi_platform::EnvironmentId::Native, "fnm", None);
+        env.update_versions(
+            vec![installed("v20.11.0", true), installed("v18.19.1", false)],
+            false,
+            true,
+        );
+        assert_eq!(
+            health_tooltip(&env, &[remote("v20.12.0"), remote("v18.20.0")]),
+            "Versi \u{2014} Node 20.11.0 default, 2 updates available"
+        );
+        assert_eq!(
+            health_tooltip(&env, &[remote("v20.11.0")]),
+            "Versi \u{2014} Node 20.11.0 default"
+        );
+    }
+
+    #[test]
+    fn test_health_tooltip_without_default() {
+        let mut env = EnvironmentState::new(versi_platform::EnvironmentId::Native, "fnm", None);
+        assert_eq!(health_tooltip(&env, &[]), "Versi");
+
+        env.update_versions(vec![installed("v18.19.1", false)], false, true);
+        assert_eq!(
+            health_tooltip(&env, &[remote("v18.20.0")]),
+            "Versi \u{2014} 1 update available"
+        );
+    }
+
+    #[test]
+    fn test_cap_tray_versions_retains_old_default() {
+        let versions = vec![
+            installed("v16.20.2", true),
+            installed("v22.1.0", false),
+            installed("v20.11.0", false),
+            installed("v18.19.1", false),
+        ];
+        let (picked, omitted) = cap_tray_versions(&versions, 2);
+        assert_eq!(omitted, 2);
+        assert_eq!(picked[0].version, "v22.1.0");
+        assert_eq!(picked[1].version, "v16.20.2");
+        assert!(picked[1].is_default);
+    }
+}
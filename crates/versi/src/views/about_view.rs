@@ -44,8 +44,44 @@ pub fn view<'a>(state: &'a MainState) -> Element<'a, Message> {
             ))
             .style(styles::secondary_button)
             .padding([6, 12]),
+            button(
+                row![text("Report an Issue").size(12), icon::arrow_up_right(12.0),]
+                    .spacing(4)
+                    .align_y(Alignment::Center)
+            )
+            .on_press(Message::ReportIssue)
+            .style(styles::secondary_button)
+            .padding([6, 12]),
         ]
         .spacing(8),
+        Space::new().height(16),
+        text("Credits").size(14),
+        Space::new().height(4),
+        text("Built with the Iced GUI framework and the Tokio async runtime")
+            .size(11)
+            .color(iced::Color::from_rgb8(142, 142, 147)),
+        text("Version management powered by fnm and nvm")
+            .size(11)
+            .color(iced::Color::from_rgb8(142, 142, 147)),
+        Space::new().height(16),
+        text("License").size(14),
+        Space::new().height(4),
+        text("Versi is free software, released under the GNU GPL v3")
+            .size(11)
+            .color(iced::Color::from_rgb8(142, 142, 147)),
+        button(
+            row![
+                text("Read the license").size(12),
+                icon::arrow_up_right(12.0),
+            ]
+            .spacing(4)
+            .align_y(Alignment::Center)
+        )
+        .on_press(Message::OpenLink(
+            "https://www.gnu.org/licenses/gpl-3.0.html".to_string()
+        ))
+        .style(styles::secondary_button)
+        .padding([6, 12]),
     ]
     .spacing(4)
     .width(Length::Fill);
@@ -5,13 +5,90 @@ use crate::message::Message;
 use crate::state::{MainState, NetworkStatus};
 use crate::theme::styles;
 
-pub(super) fn contextual_banners<'a>(state: &'a MainState) -> Option<Element<'a, Message>> {
+pub(super) fn contextual_banners<'a>(
+    state: &'a MainState,
+    settings: &'a crate::settings::AppSettings,
+    compact: bool,
+) -> Option<Element<'a, Message>> {
     let env = state.active_environment();
     let schedule = state.available_versions.schedule.as_ref();
     let remote = &state.available_versions.versions;
 
     let mut banners: Vec<Element<Message>> = Vec::new();
 
+    // In a narrow window the operation banners dock more tightly so they
+    // cover less of the version list.
+    let op_padding = if compact { [8, 12] } else { [12, 16] };
+
+    // Live status for in-flight installs, driven entirely by the last
+    // progress stored on the operation. Because this is a pure read of
+    // state, reopening the window (tray show, WindowOpened) repaints the
+    // current percent immediately rather than waiting for the next
+    // progress event.
+    let installs = &state.operation_queue.active_installs;
+    if compact && installs.len() > 1 {
+        // One line per install would fill a small window; summarize instead.
+        banners.push(
+            button(
+                row![
+                    text(format!("Installing {} versions...", installs.len())).size(13),
+                    Space::new().width(Length::Fill),
+                ]
+                .align_y(Alignment::Center),
+            )
+            .style(styles::banner_button_info)
+            .padding(op_padding)
+            .width(Length::Fill)
+            .into(),
+        );
+    } else {
+        for op in installs {
+            if let crate::state::Operation::Install {
+                version, progress, ..
+            } = op
+            {
+                banners.push(
+                    button(
+                        row![
+                            text(install_status_label(
+                                version,
+                                progress,
+                                &settings.progress_verbosity
+                            ))
+                            .size(13),
+                            Space::new().width(Length::Fill),
+                        ]
+                        .align_y(Alignment::Center),
+                    )
+                    .style(styles::banner_button_info)
+                    .padding(op_padding)
+                    .width(Length::Fill)
+                    .into(),
+                );
+            }
+        }
+    }
+
+    // Known-problematic backend versions can leave the bar above stuck at an
+    // early phase; say so up front instead of letting it look like a hang.
+    if !state.operation_queue.active_installs.is_empty()
+        && let Some(note) = &env.progress_note
+    {
+        banners.push(
+            button(
+                row![
+                    text(note.as_str()).size(13),
+                    Space::new().width(Length::Fill),
+                ]
+                .align_y(Alignment::Center),
+            )
+            .style(styles::banner_button_info)
+            .padding(op_padding)
+            .width(Length::Fill)
+            .into(),
+        );
+    }
+
     match state.available_versions.network_status() {
         NetworkStatus::Offline => {
             banners.push(
@@ -69,30 +146,7 @@ pub(super) fn contextual_banners<'a>(state: &'a MainState) -> Option<Element<'a,
         );
     }
 
-    let update_count = {
-        let mut latest_by_major: std::collections::HashMap<u32, &versi_backend::NodeVersion> =
-            std::collections::HashMap::new();
-        for v in remote {
-            latest_by_major
-                .entry(v.version.major)
-                .and_modify(|existing| {
-                    if &v.version > *existing {
-                        *existing = &v.version;
-                    }
-                })
-                .or_insert(&v.version);
-        }
-
-        env.version_groups
-            .iter()
-            .filter(|group| {
-                let installed_latest = group.versions.iter().map(|v| &v.version).max();
-                latest_by_major.get(&group.major).is_some_and(|latest| {
-                    installed_latest.is_some_and(|installed| *latest > installed)
-                })
-            })
-            .count()
-    };
+    let update_count = env.majors_with_updates(remote);
 
     if update_count > 0 {
         banners.push(
@@ -121,11 +175,105 @@ pub(super) fn contextual_banners<'a>(state: &'a MainState) -> Option<Element<'a,
         );
     }
 
+    if let Some((new_default, previous)) = &state.undo_default {
+        // Crossing a major boundary breaks globally installed native addons
+        // until they are rebuilt, which is worth calling out here since Versi
+        // can't run the rebuild itself.
+        let crossed_major = new_default
+            .parse::<versi_backend::NodeVersion>()
+            .ok()
+            .zip(previous.parse::<versi_backend::NodeVersion>().ok())
+            .is_some_and(|(new, prev)| new.major != prev.major);
+        let label = if crossed_major {
+            format!(
+                "Default is now Node {} (was {}) \u{2014} native modules may need npm rebuild",
+                new_default, previous
+            )
+        } else {
+            format!("Default is now Node {} (was {})", new_default, previous)
+        };
+        banners.push(
+            button(
+                row![
+                    text(label).size(13),
+                    Space::new().width(Length::Fill),
+                    text("Undo").size(13),
+                ]
+                .align_y(Alignment::Center),
+            )
+            .on_press(Message::UndoSetDefault)
+            .style(styles::banner_button_info)
+            .padding([12, 16])
+            .width(Length::Fill)
+            .into(),
+        );
+    }
+
+    if let Some(pin) = &state.project_pin
+        && let Some(resolved) = versi_core::resolve_pin(&pin.raw, remote)
+    {
+        let version_str = resolved.version.to_string();
+        let installed = env
+            .installed_versions
+            .iter()
+            .any(|v| v.version == resolved.version);
+        let in_flight = state.operation_queue.is_current_version(&version_str)
+            || state.operation_queue.has_pending_for_version(&version_str);
+
+        if !installed && !in_flight {
+            let pin_file = pin
+                .file
+                .file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            banners.push(
+                button(
+                    row![
+                        text(format!(
+                            "This project pins Node {} ({})",
+                            version_str, pin_file
+                        ))
+                        .size(13),
+                        Space::new().width(Length::Fill),
+                        text("Install").size(13),
+                    ]
+                    .align_y(Alignment::Center),
+                )
+                .on_press(Message::InstallProjectPin)
+                .style(styles::banner_button_info)
+                .padding([12, 16])
+                .width(Length::Fill)
+                .into(),
+            );
+        }
+    }
+
+    let pending_count = state.operation_queue.pending.len();
+    if pending_count > 1 {
+        banners.push(
+            button(
+                row![
+                    text(format!("{} operations queued", pending_count)).size(13),
+                    Space::new().width(Length::Fill),
+                    text("Clear Queue").size(13),
+                ]
+                .align_y(Alignment::Center),
+            )
+            .on_press(Message::ClearQueue)
+            .style(styles::banner_button_info)
+            .padding([12, 16])
+            .width(Length::Fill)
+            .into(),
+        );
+    }
+
     let eol_count = schedule
         .map(|s| {
             env.version_groups
                 .iter()
-                .filter(|g| !s.is_active(g.major))
+                .filter(|g| {
+                    !s.is_active(g.major) && !settings.ignored_eol_majors.contains(&g.major)
+                })
                 .map(|g| g.versions.len())
                 .sum::<usize>()
         })
@@ -164,3 +312,44 @@ pub(super) fn contextual_banners<'a>(state: &'a MainState) -> Option<Element<'a,
         Some(column(banners).spacing(8).into())
     }
 }
+
+/// Status line for an in-flight install, at the detail level the user picked:
+/// minimal is just the version, normal adds phase and percent (mirroring the
+/// onboarding progress label), detailed appends speed and ETA when the
+/// backend reports them.
+fn install_status_label(
+    version: &str,
+    progress: &versi_backend::InstallProgress,
+    verbosity: &crate::settings::ProgressVerbosity,
+) -> String {
+    if *verbosity == crate::settings::ProgressVerbosity::Minimal {
+        return format!("Installing Node {}...", version);
+    }
+
+    let phase = match progress.phase {
+        versi_backend::InstallPhase::Starting => "Preparing",
+        versi_backend::InstallPhase::Downloading => "Downloading",
+        versi_backend::InstallPhase::Extracting => "Extracting",
+        versi_backend::InstallPhase::Installing => "Installing",
+        versi_backend::InstallPhase::Complete => "Finishing up",
+        versi_backend::InstallPhase::Failed => "Failed",
+    };
+    let mut label = match progress.percent {
+        Some(percent) => format!(
+            "Installing Node {} \u{2014} {}... {:.0}%",
+            version, phase, percent
+        ),
+        None => format!("Installing Node {} \u{2014} {}...", version, phase),
+    };
+
+    if *verbosity == crate::settings::ProgressVerbosity::Detailed {
+        if let Some(speed) = &progress.speed {
+            label.push_str(&format!(" \u{00b7} {}", speed));
+        }
+        if let Some(eta) = &progress.eta {
+            label.push_str(&format!(" \u{00b7} {} left", eta));
+        }
+    }
+
+    label
+}
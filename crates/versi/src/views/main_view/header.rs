@@ -1,23 +1,87 @@
-use iced::widget::{Space, button, container, row, text};
+use iced::widget::{Space, button, column, container, pick_list, row, text, tooltip};
 use iced::{Alignment, Element, Length};
 
 use crate::icon;
 use crate::message::Message;
+use crate::settings::AppSettings;
 use crate::state::MainState;
 use crate::theme::styles;
 use crate::widgets::helpers::nav_icons;
 
-pub(super) fn header_view<'a>(state: &'a MainState) -> Element<'a, Message> {
+pub(super) fn header_view<'a>(
+    state: &'a MainState,
+    settings: &'a AppSettings,
+    compact: bool,
+) -> Element<'a, Message> {
     let env = state.active_environment();
 
-    let subtitle = match &env.backend_version {
-        Some(v) => format!("{} {}", state.backend_name, v),
-        None => state.backend_name.to_string(),
-    };
+    let mut left = row![].spacing(8).align_y(Alignment::Center);
 
-    let mut left = row![text(subtitle).size(14),]
-        .spacing(8)
-        .align_y(Alignment::Center);
+    // With several managers detected the subtitle doubles as a switcher;
+    // picking one rebuilds the backend from that provider and re-initializes.
+    if state.detected_backends.len() > 1 {
+        let options: Vec<String> = state
+            .detected_backends
+            .iter()
+            .map(|name| name.to_string())
+            .collect();
+        left = left.push(
+            pick_list(
+                options,
+                Some(state.backend_name.to_string()),
+                Message::PreferredBackendChanged,
+            )
+            .text_size(13)
+            .padding([2, 8]),
+        );
+        if let Some(v) = &env.backend_version {
+            left = left.push(text(v.clone()).size(14));
+        }
+    } else {
+        let subtitle = match &env.backend_version {
+            Some(v) => format!("{} {}", state.backend_name, v),
+            None => state.backend_name.to_string(),
+        };
+        left = left.push(text(subtitle).size(14));
+    }
+
+    let pinned_major = settings
+        .pinned_default_majors
+        .get(&env.id.settings_key())
+        .copied();
+
+    // Alias chips are the first thing to go in a narrow window; they are
+    // purely informational and come back once there is room again.
+    if !compact {
+        // A floating pin replaces the plain default alias chip with the
+        // resolved form, so the header says the default follows the major.
+        if let Some(major) = pinned_major {
+            let label = match &env.default_version {
+                Some(v) => format!("Default: {} (resolves to {})", major, v),
+                None => format!("Default: {} (nothing installed)", major),
+            };
+            left = left.push(
+                container(text(label).size(11))
+                    .padding([2, 6])
+                    .style(styles::badge_default),
+            );
+        }
+        for (alias, version) in &env.aliases {
+            if alias == "default" && pinned_major.is_some() {
+                continue;
+            }
+            let chip_style = if alias == "default" {
+                styles::badge_default
+            } else {
+                styles::badge_arch
+            };
+            left = left.push(
+                container(text(format!("{} \u{2192} {}", alias, version)).size(11))
+                    .padding([2, 6])
+                    .style(chip_style),
+            );
+        }
+    }
 
     if let Some(update) = &state.app_update {
         left = left.push(
@@ -36,36 +100,102 @@ pub(super) fn header_view<'a>(state: &'a MainState) -> Element<'a, Message> {
             .style(styles::app_update_button)
             .padding(0),
         );
+        left = left.push(
+            button(text("Skip").size(10))
+                .on_press(Message::SkipAppUpdate)
+                .style(styles::ghost_button)
+                .padding([2, 6]),
+        );
     }
 
     if let Some(update) = &state.backend_update {
-        left = left.push(
-            button(
-                container(
-                    row![
-                        text(format!(
-                            "{} {} available",
-                            state.backend_name, update.latest_version
-                        ))
-                        .size(11),
-                        icon::arrow_up_right(11.0),
-                    ]
-                    .spacing(2)
-                    .align_y(Alignment::Center),
-                )
-                .padding([2, 8]),
+        let badge = button(
+            container(
+                row![
+                    text(format!(
+                        "{} {} available",
+                        state.backend_name, update.latest_version
+                    ))
+                    .size(11),
+                    icon::arrow_up_right(11.0),
+                ]
+                .spacing(2)
+                .align_y(Alignment::Center),
             )
-            .on_press(Message::OpenBackendUpdate)
-            .style(styles::app_update_button)
-            .padding(0),
-        );
+            .padding([2, 8]),
+        )
+        .on_press(Message::OpenBackendUpdate)
+        .style(styles::app_update_button)
+        .padding(0);
+
+        // Point out when the update would unlock install options the
+        // current backend version is too old for.
+        let capabilities = state.backend.capabilities();
+        let mut locked = Vec::new();
+        if !capabilities.supports_corepack {
+            locked.push("corepack");
+        }
+        if !capabilities.supports_resolve_engines {
+            locked.push("resolve-engines");
+        }
+
+        if locked.is_empty() {
+            left = left.push(badge);
+        } else {
+            let label = format!(
+                "Updating {} enables {} support",
+                state.backend_name,
+                locked.join(" and ")
+            );
+            left = left.push(tooltip(
+                badge,
+                container(text(label).size(12))
+                    .padding([4, 8])
+                    .style(styles::tooltip_container),
+                tooltip::Position::Bottom,
+            ));
+        }
     }
 
-    row![
-        left,
-        Space::new().width(Length::Fill),
-        nav_icons(&state.view, state.refresh_rotation),
-    ]
-    .align_y(Alignment::Center)
-    .into()
+    if compact {
+        // Narrow windows: the nav icons would make the badge row wrap, so
+        // they collapse behind an overflow toggle and dock on their own row.
+        let toggle_style = if state.header_menu_open {
+            styles::ghost_button_active as fn(&iced::Theme, button::Status) -> button::Style
+        } else {
+            styles::ghost_button
+        };
+        let mut layout = column![
+            row![
+                left,
+                Space::new().width(Length::Fill),
+                button(icon::more_horizontal(16.0))
+                    .on_press(Message::HeaderMenuToggled)
+                    .style(toggle_style)
+                    .padding([4, 6]),
+            ]
+            .align_y(Alignment::Center),
+        ]
+        .spacing(4);
+
+        if state.header_menu_open {
+            layout = layout.push(
+                row![
+                    Space::new().width(Length::Fill),
+                    nav_icons(&state.view, state.refresh_rotation),
+                ]
+                .align_y(Alignment::Center),
+            );
+        }
+
+        layout.into()
+    } else {
+        row![
+            left,
+            Space::new().width(Length::Fill),
+            nav_icons(&state.view, state.refresh_rotation),
+        ]
+        .align_y(Alignment::Center)
+        .into()
+    }
 }
@@ -1,6 +1,6 @@
 mod banners;
 mod header;
-mod modals;
+pub mod modals;
 pub mod search;
 mod tabs;
 
@@ -12,8 +12,19 @@ use crate::settings::AppSettings;
 use crate::state::MainState;
 use crate::widgets::{toast_container, version_list};
 
-pub fn view<'a>(state: &'a MainState, settings: &'a AppSettings) -> Element<'a, Message> {
-    let header = header::header_view(state);
+/// Below this window width the header collapses its navigation icons behind
+/// an overflow toggle and operation banners dock more compactly.
+pub(crate) const COMPACT_WIDTH_BREAKPOINT: f32 = 720.0;
+
+pub fn view<'a>(
+    state: &'a MainState,
+    settings: &'a AppSettings,
+    window_width: Option<f32>,
+) -> Element<'a, Message> {
+    // Width is unknown until the first resize event; assume the regular
+    // layout rather than flashing the compact one at startup.
+    let compact = window_width.is_some_and(|w| w < COMPACT_WIDTH_BREAKPOINT);
+    let header = header::header_view(state, settings, compact);
     let search_bar = search::search_bar_view(state);
     let hovered = if state.modal.is_some() {
         &None
@@ -24,9 +35,26 @@ pub fn view<'a>(state: &'a MainState, settings: &'a AppSettings) -> Element<'a,
         state.active_environment(),
         &state.search_query,
         &state.available_versions.versions,
+        state.available_versions.loading,
         state.available_versions.schedule.as_ref(),
         &state.operation_queue,
         hovered,
+        state.range_match.as_ref(),
+        settings
+            .pinned_default_majors
+            .get(&state.active_environment().id.settings_key())
+            .copied(),
+        &settings.group_sort,
+        &settings.last_used,
+        &settings.install_sources,
+        settings.show_all_patches,
+        settings.group_by_minor,
+        &settings.ignored_eol_majors,
+        state.backend.capabilities().supports_exec,
+        // Reveal needs a locally reachable data dir; WSL paths aren't
+        // accessible from the host file manager.
+        state.backend.backend_info().data_dir.is_some()
+            && state.active_environment().id == versi_platform::EnvironmentId::Native,
     );
 
     let mut main_column = column![].spacing(0);
@@ -48,7 +76,7 @@ pub fn view<'a>(state: &'a MainState, settings: &'a AppSettings) -> Element<'a,
     .spacing(12);
 
     if state.search_query.is_empty()
-        && let Some(banner_content) = banners::contextual_banners(state)
+        && let Some(banner_content) = banners::contextual_banners(state, settings, compact)
     {
         content_column = content_column.push(container(banner_content).padding(right_inset));
     }
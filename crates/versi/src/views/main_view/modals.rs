@@ -1,20 +1,69 @@
-use iced::widget::{Space, button, column, container, mouse_area, row, text};
-use iced::{Element, Length};
+use iced::widget::{
+    Space, button, column, container, mouse_area, row, scrollable, text, text_input, toggler,
+};
+use iced::{Alignment, Element, Length};
+
+use versi_backend::ExecOutput;
 
 use crate::message::Message;
 use crate::settings::AppSettings;
-use crate::state::{MainState, Modal};
+use crate::state::{BrowseFilter, MainState, Modal};
 use crate::theme::styles;
 
-pub(super) fn modal_overlay<'a>(
+pub fn modal_overlay<'a>(
     content: Element<'a, Message>,
     modal: &'a Modal,
-    _state: &'a MainState,
-    _settings: &'a AppSettings,
+    state: &'a MainState,
+    settings: &'a AppSettings,
 ) -> Element<'a, Message> {
     let modal_content: Element<Message> = match modal {
+        Modal::LogViewer { content } => log_viewer_view(content),
+        Modal::BrowseVersions {
+            query,
+            filter,
+            limit,
+        } => browse_versions_view(query, filter, *limit, state),
+        Modal::RunCommand {
+            version,
+            input,
+            running,
+            output,
+        } => run_command_view(version, input, *running, output.as_ref()),
+        Modal::ConfirmClose => confirm_close_view(),
+        Modal::ConfirmQuitDuringOperations => confirm_quit_during_operations_view(),
+        Modal::ConfirmUninstall {
+            version,
+            is_default,
+            is_last,
+            is_in_use,
+            install_path,
+            disk_size,
+        } => confirm_uninstall_view(
+            version,
+            *is_default,
+            *is_last,
+            *is_in_use,
+            install_path.as_deref(),
+            *disk_size,
+            settings.skip_uninstall_confirm,
+        ),
+        Modal::AppUpdateProgress {
+            version,
+            release_url,
+            progress,
+        } => app_update_progress_view(version, release_url, progress),
         Modal::ConfirmBulkUpdateMajors { versions } => confirm_bulk_update_view(versions),
-        Modal::ConfirmBulkUninstallEOL { versions } => confirm_bulk_uninstall_eol_view(versions),
+        Modal::ConfirmInstallFromProjects { versions } => {
+            confirm_install_from_projects_view(versions)
+        }
+        Modal::ConfirmBulkUninstallEOL { versions, kept } => {
+            confirm_bulk_uninstall_eol_view(versions, kept)
+        }
+        Modal::ConfirmMigrateFromNvm {
+            versions,
+            default_version,
+            set_default,
+        } => confirm_migrate_from_nvm_view(versions, default_version.as_deref(), *set_default),
         Modal::ConfirmBulkUninstallMajor { major, versions } => {
             confirm_bulk_uninstall_major_view(*major, versions)
         }
@@ -23,6 +72,7 @@ pub(super) fn modal_overlay<'a>(
             versions,
             keeping,
         } => confirm_bulk_uninstall_major_except_latest_view(*major, versions, keeping),
+        Modal::ConfirmClearCache { size } => confirm_clear_cache_view(*size),
     };
 
     let backdrop = mouse_area(
@@ -41,11 +91,19 @@ pub(super) fn modal_overlay<'a>(
     )
     .on_press(Message::CloseModal);
 
+    // Modals with monospace output get a wider frame than the confirmation
+    // dialogs.
+    let max_width = match modal {
+        Modal::LogViewer { .. } => 640,
+        Modal::BrowseVersions { .. } | Modal::RunCommand { .. } => 560,
+        _ => 480,
+    };
+
     let modal_container = mouse_area(
         container(modal_content)
             .style(styles::modal_container)
             .padding(28)
-            .max_width(480),
+            .max_width(max_width),
     )
     .on_press(Message::NoOp);
 
@@ -58,6 +116,541 @@ pub(super) fn modal_overlay<'a>(
     iced::widget::stack![content, backdrop, modal_layer].into()
 }
 
+fn browse_versions_view<'a>(
+    query: &'a str,
+    filter: &'a BrowseFilter,
+    limit: usize,
+    state: &'a MainState,
+) -> Element<'a, Message> {
+    let schedule = state.available_versions.schedule.as_ref();
+    let installed_set: std::collections::HashSet<String> = state
+        .active_environment()
+        .installed_versions
+        .iter()
+        .map(|v| v.version.to_string())
+        .collect();
+
+    let query_lower = query.to_lowercase();
+    let mut filtered: Vec<&versi_backend::RemoteVersion> = state
+        .available_versions
+        .versions
+        .iter()
+        .filter(|v| {
+            let matches_query = query.is_empty()
+                || v.version.to_string().contains(query)
+                || v.lts_codename
+                    .as_ref()
+                    .map(|c| c.to_lowercase().contains(&query_lower))
+                    .unwrap_or(false);
+            if !matches_query {
+                return false;
+            }
+            let is_eol = schedule
+                .map(|s| !s.is_active(v.version.major))
+                .unwrap_or(false);
+            match filter {
+                BrowseFilter::All => true,
+                BrowseFilter::Lts => v.lts_codename.is_some(),
+                BrowseFilter::Current => !is_eol && v.lts_codename.is_none(),
+                BrowseFilter::Eol => is_eol,
+            }
+        })
+        .collect();
+    filtered.sort_by(|a, b| b.version.cmp(&a.version));
+
+    let filter_button = |label: &'static str, value: BrowseFilter| -> Element<'a, Message> {
+        let is_selected = *filter == value;
+        button(text(label).size(12))
+            .on_press(Message::BrowseFilterChanged(value))
+            .style(if is_selected {
+                styles::primary_button
+            } else {
+                styles::secondary_button
+            })
+            .padding([6, 12])
+            .into()
+    };
+
+    let mut list = column![].spacing(4);
+    let mut current_major: Option<u32> = None;
+    for v in filtered.iter().take(limit) {
+        if current_major != Some(v.version.major) {
+            if current_major.is_some() {
+                list = list.push(Space::new().height(8));
+            }
+            current_major = Some(v.version.major);
+            let mut header = row![text(format!("Node {}", v.version.major)).size(14)]
+                .spacing(8)
+                .align_y(Alignment::Center);
+            if let Some(codename) = schedule.and_then(|s| s.codename(v.version.major)) {
+                header = header.push(
+                    text(codename)
+                        .size(11)
+                        .color(iced::Color::from_rgb8(142, 142, 147)),
+                );
+            }
+            list = list.push(header);
+        }
+        list = list.push(
+            crate::widgets::version_list::available::available_version_row(
+                v,
+                schedule,
+                &state.operation_queue,
+                &installed_set,
+                &state.hovered_version,
+            ),
+        );
+    }
+
+    if filtered.is_empty() {
+        list = list.push(
+            text("No versions match")
+                .size(12)
+                .color(iced::Color::from_rgb8(142, 142, 147)),
+        );
+    } else if filtered.len() > limit {
+        list = list.push(
+            button(text(format!("Show More ({} remaining)", filtered.len() - limit)).size(12))
+                .on_press(Message::BrowseShowMore)
+                .style(styles::secondary_button)
+                .padding([6, 12]),
+        );
+    }
+
+    column![
+        text("Browse Versions").size(20),
+        Space::new().height(12),
+        text_input("Filter versions...", query)
+            .on_input(Message::BrowseQueryChanged)
+            .size(13)
+            .padding([8, 12]),
+        Space::new().height(8),
+        row![
+            filter_button("All", BrowseFilter::All),
+            filter_button("LTS", BrowseFilter::Lts),
+            filter_button("Current", BrowseFilter::Current),
+            filter_button("End-of-Life", BrowseFilter::Eol),
+        ]
+        .spacing(8),
+        Space::new().height(12),
+        scrollable(container(list).padding(iced::Padding::default().right(12.0)))
+            .height(iced::Length::Fixed(360.0)),
+        Space::new().height(24),
+        row![
+            Space::new().width(Length::Fill),
+            button(text("Close").size(13))
+                .on_press(Message::CloseModal)
+                .style(styles::primary_button)
+                .padding([10, 20]),
+        ]
+        .spacing(16),
+    ]
+    .spacing(4)
+    .width(Length::Fill)
+    .into()
+}
+
+fn log_viewer_view(content: &str) -> Element<'_, Message> {
+    let body: Element<Message> = if content.is_empty() {
+        text("The log file is empty.")
+            .size(12)
+            .color(iced::Color::from_rgb8(142, 142, 147))
+            .into()
+    } else {
+        text(content).size(11).font(iced::Font::MONOSPACE).into()
+    };
+
+    column![
+        text("Log File").size(20),
+        Space::new().height(12),
+        scrollable(container(body).padding(iced::Padding::default().right(12.0)))
+            .height(iced::Length::Fixed(360.0))
+            .anchor_bottom(),
+        Space::new().height(24),
+        row![
+            button(text("Refresh").size(13))
+                .on_press(Message::ViewLogFile)
+                .style(styles::secondary_button)
+                .padding([10, 20]),
+            Space::new().width(Length::Fill),
+            button(text("Close").size(13))
+                .on_press(Message::CloseModal)
+                .style(styles::primary_button)
+                .padding([10, 20]),
+        ]
+        .spacing(16),
+    ]
+    .spacing(4)
+    .width(Length::Fill)
+    .into()
+}
+
+fn run_command_view<'a>(
+    version: &'a str,
+    input: &'a str,
+    running: bool,
+    output: Option<&'a Result<ExecOutput, String>>,
+) -> Element<'a, Message> {
+    let mut content = column![
+        text(format!("Run under Node {}", version)).size(20),
+        Space::new().height(4),
+        text("Runs a command with this version active, without changing your shell.")
+            .size(12)
+            .color(iced::Color::from_rgb8(142, 142, 147)),
+        Space::new().height(12),
+    ]
+    .spacing(4)
+    .width(Length::Fill);
+
+    let mut command_input = text_input("npm --version", input).size(13).padding([8, 12]);
+    if !running {
+        command_input = command_input
+            .on_input(Message::RunCommandInputChanged)
+            .on_submit(Message::RunCommandSubmitted);
+    }
+
+    let run_button = if running {
+        button(text("Running...").size(13))
+            .style(styles::secondary_button)
+            .padding([8, 16])
+    } else {
+        button(text("Run").size(13))
+            .on_press(Message::RunCommandSubmitted)
+            .style(styles::primary_button)
+            .padding([8, 16])
+    };
+
+    content = content.push(
+        row![command_input, run_button]
+            .spacing(8)
+            .align_y(Alignment::Center),
+    );
+
+    if let Some(result) = output {
+        content = content.push(Space::new().height(12));
+        match result {
+            Ok(out) => {
+                let mut output_col = column![].spacing(4);
+                if !out.stdout.trim().is_empty() {
+                    output_col = output_col.push(
+                        text(out.stdout.trim_end())
+                            .size(11)
+                            .font(iced::Font::MONOSPACE),
+                    );
+                }
+                if !out.stderr.trim().is_empty() {
+                    output_col = output_col.push(
+                        text(out.stderr.trim_end())
+                            .size(11)
+                            .font(iced::Font::MONOSPACE)
+                            .color(iced::Color::from_rgb8(255, 149, 0)),
+                    );
+                }
+                if out.stdout.trim().is_empty() && out.stderr.trim().is_empty() {
+                    output_col = output_col.push(
+                        text("(no output)")
+                            .size(11)
+                            .color(iced::Color::from_rgb8(142, 142, 147)),
+                    );
+                }
+                content = content.push(
+                    scrollable(container(output_col).padding(iced::Padding::default().right(12.0)))
+                        .height(Length::Fixed(200.0)),
+                );
+                if let Some(code) = out.exit_code
+                    && code != 0
+                {
+                    content = content.push(Space::new().height(4));
+                    content = content.push(
+                        text(format!("Exited with code {}", code))
+                            .size(11)
+                            .color(iced::Color::from_rgb8(255, 69, 58)),
+                    );
+                }
+            }
+            Err(error) => {
+                content = content.push(
+                    text(error)
+                        .size(12)
+                        .color(iced::Color::from_rgb8(255, 69, 58)),
+                );
+            }
+        }
+    }
+
+    content = content.push(Space::new().height(24));
+    content = content.push(
+        row![
+            Space::new().width(Length::Fill),
+            button(text("Close").size(13))
+                .on_press(Message::CloseModal)
+                .style(styles::secondary_button)
+                .padding([10, 20]),
+        ]
+        .spacing(16),
+    );
+
+    content.into()
+}
+
+fn confirm_close_view() -> Element<'static, Message> {
+    column![
+        text("Close Versi?").size(20),
+        Space::new().height(12),
+        text("Quit the app, or keep it running in the system tray?").size(14),
+        Space::new().height(4),
+        text("You can change this in Settings under \"System Tray\".")
+            .size(12)
+            .color(iced::Color::from_rgb8(142, 142, 147)),
+        Space::new().height(24),
+        row![
+            button(text("Cancel").size(13))
+                .on_press(Message::CloseModal)
+                .style(styles::secondary_button)
+                .padding([10, 20]),
+            Space::new().width(Length::Fill),
+            button(text("Minimize to Tray").size(13))
+                .on_press(Message::ConfirmCloseMinimize)
+                .style(styles::secondary_button)
+                .padding([10, 20]),
+            button(text("Quit").size(13))
+                .on_press(Message::ConfirmCloseQuit)
+                .style(styles::primary_button)
+                .padding([10, 20]),
+        ]
+        .spacing(16),
+    ]
+    .spacing(4)
+    .width(Length::Fill)
+    .into()
+}
+
+fn confirm_quit_during_operations_view() -> Element<'static, Message> {
+    column![
+        text("Operations are in progress \u{2014} quit anyway?").size(20),
+        Space::new().height(12),
+        text("Quitting now kills the running operations mid-way and can leave partial installs behind.")
+            .size(14),
+        Space::new().height(4),
+        text("You can turn this confirmation off in Settings under \"When Closing the Window\".")
+            .size(12)
+            .color(iced::Color::from_rgb8(142, 142, 147)),
+        Space::new().height(24),
+        row![
+            button(text("Cancel").size(13))
+                .on_press(Message::CloseModal)
+                .style(styles::secondary_button)
+                .padding([10, 20]),
+            Space::new().width(Length::Fill),
+            button(text("Quit Anyway").size(13))
+                .on_press(Message::ConfirmQuitAnyway)
+                .style(styles::danger_button)
+                .padding([10, 20]),
+        ]
+        .spacing(16),
+    ]
+    .spacing(4)
+    .width(Length::Fill)
+    .into()
+}
+
+fn confirm_clear_cache_view<'a>(size: u64) -> Element<'a, Message> {
+    column![
+        text("Clear the download cache?").size(20),
+        Space::new().height(12),
+        text(format!(
+            "This frees {:.1} MB of cached downloads. The backend re-downloads archives as needed.",
+            size as f64 / (1024.0 * 1024.0)
+        ))
+        .size(14),
+        Space::new().height(24),
+        row![
+            button(text("Cancel").size(13))
+                .on_press(Message::CloseModal)
+                .style(styles::secondary_button)
+                .padding([10, 20]),
+            Space::new().width(Length::Fill),
+            button(text("Clear Cache").size(13))
+                .on_press(Message::ConfirmClearCache)
+                .style(styles::danger_button)
+                .padding([10, 20]),
+        ]
+        .spacing(16),
+    ]
+    .spacing(4)
+    .width(Length::Fill)
+    .into()
+}
+
+fn app_update_progress_view<'a>(
+    version: &'a str,
+    release_url: &'a str,
+    progress: &'a versi_core::SelfUpdateProgress,
+) -> Element<'a, Message> {
+    use versi_core::SelfUpdateProgress;
+
+    let mut content = column![
+        text(format!("Updating Versi to v{}", version)).size(20),
+        Space::new().height(12),
+    ]
+    .spacing(4)
+    .width(Length::Fill);
+
+    match progress {
+        SelfUpdateProgress::Downloading { percent } => {
+            let label = match percent {
+                Some(p) => format!("Downloading... {:.0}%", p),
+                None => "Downloading...".to_string(),
+            };
+            content = content.push(text(label).size(14));
+        }
+        SelfUpdateProgress::Applying => {
+            content = content.push(text("Installing...").size(14));
+        }
+        SelfUpdateProgress::Complete => {
+            content = content.push(text("Update installed.").size(14));
+            content = content.push(Space::new().height(24));
+            content = content.push(
+                row![
+                    button(text("Later").size(13))
+                        .on_press(Message::CancelBulkOperation)
+                        .style(styles::secondary_button)
+                        .padding([10, 20]),
+                    Space::new().width(Length::Fill),
+                    button(text("Restart Now").size(13))
+                        .on_press(Message::RestartForUpdate)
+                        .style(styles::primary_button)
+                        .padding([10, 20]),
+                ]
+                .spacing(16),
+            );
+        }
+        SelfUpdateProgress::Failed(error) => {
+            content = content.push(
+                text(error.as_str())
+                    .size(12)
+                    .color(iced::Color::from_rgb8(255, 69, 58)),
+            );
+            content = content.push(Space::new().height(24));
+            content = content.push(
+                row![
+                    button(text("Close").size(13))
+                        .on_press(Message::CancelBulkOperation)
+                        .style(styles::secondary_button)
+                        .padding([10, 20]),
+                    Space::new().width(Length::Fill),
+                    button(text("Open Release Page").size(13))
+                        .on_press(Message::OpenLink(release_url.to_string()))
+                        .style(styles::primary_button)
+                        .padding([10, 20]),
+                ]
+                .spacing(16),
+            );
+        }
+    }
+
+    content.into()
+}
+
+fn confirm_uninstall_view<'a>(
+    version: &'a str,
+    is_default: bool,
+    is_last: bool,
+    is_in_use: bool,
+    install_path: Option<&'a std::path::Path>,
+    disk_size: Option<u64>,
+    skip_confirm: bool,
+) -> Element<'a, Message> {
+    let mut content = column![
+        text(format!("Uninstall Node {}?", version)).size(20),
+        Space::new().height(12),
+        text("This will remove the version from your system.").size(14),
+    ]
+    .spacing(4)
+    .width(Length::Fill);
+
+    if let Some(path) = install_path {
+        content = content.push(Space::new().height(8));
+        content = content.push(
+            text(path.display().to_string())
+                .size(11)
+                .font(iced::Font::MONOSPACE)
+                .color(iced::Color::from_rgb8(142, 142, 147)),
+        );
+    }
+
+    if is_in_use {
+        content = content.push(Space::new().height(8));
+        content = content.push(
+            text("A shell is currently using this version. Removing it can break that terminal until it is restarted.")
+                .size(12)
+                .color(iced::Color::from_rgb8(255, 69, 58)),
+        );
+    }
+
+    if is_last {
+        content = content.push(Space::new().height(8));
+        content = content.push(
+            text("This is your only installed Node version. Removing it leaves you without Node and clears the default.")
+                .size(12)
+                .color(iced::Color::from_rgb8(255, 149, 0)),
+        );
+    } else if is_default {
+        content = content.push(Space::new().height(8));
+        content = content.push(
+            text("This version is your current default.")
+                .size(12)
+                .color(iced::Color::from_rgb8(255, 149, 0)),
+        );
+    }
+
+    content = content.push(Space::new().height(12));
+    content = content.push(
+        row![
+            toggler(skip_confirm)
+                .on_toggle(Message::SkipUninstallConfirmToggled)
+                .size(18),
+            text("Don't ask again for single versions").size(12),
+        ]
+        .spacing(8)
+        .align_y(Alignment::Center),
+    );
+
+    content = content.push(Space::new().height(24));
+    content = content.push(
+        row![
+            button(text("Cancel").size(13))
+                .on_press(Message::CancelBulkOperation)
+                .style(styles::secondary_button)
+                .padding([10, 20]),
+            Space::new().width(Length::Fill),
+            button(text(uninstall_button_label(is_in_use, disk_size)).size(13))
+                .on_press(Message::ConfirmUninstall)
+                .style(styles::danger_button)
+                .padding([10, 20]),
+        ]
+        .spacing(16),
+    );
+
+    content.into()
+}
+
+fn uninstall_button_label(is_in_use: bool, disk_size: Option<u64>) -> String {
+    let base = if is_in_use {
+        "Uninstall Anyway"
+    } else {
+        "Uninstall"
+    };
+    match disk_size {
+        Some(size) => format!(
+            "{} \u{2014} frees {:.0} MB",
+            base,
+            size as f64 / (1024.0 * 1024.0)
+        ),
+        None => base.to_string(),
+    }
+}
+
 fn confirm_bulk_update_view(versions: &[(String, String)]) -> Element<'_, Message> {
     let mut version_list = column![].spacing(4);
 
@@ -106,7 +699,78 @@ fn confirm_bulk_update_view(versions: &[(String, String)]) -> Element<'_, Messag
     .into()
 }
 
-fn confirm_bulk_uninstall_eol_view(versions: &[String]) -> Element<'_, Message> {
+fn confirm_install_from_projects_view(versions: &[String]) -> Element<'_, Message> {
+    let mut version_list = column![].spacing(4);
+
+    for version in versions.iter().take(10) {
+        version_list = version_list.push(
+            text(format!("Node {}", version))
+                .size(12)
+                .color(iced::Color::from_rgb8(142, 142, 147)),
+        );
+    }
+
+    if versions.len() > 10 {
+        version_list = version_list.push(
+            text(format!("...and {} more", versions.len() - 10))
+                .size(11)
+                .color(iced::Color::from_rgb8(142, 142, 147)),
+        );
+    }
+
+    column![
+        text("Install Pinned Versions?").size(20),
+        Space::new().height(12),
+        text(format!(
+            "Your projects pin {} version(s) that aren't installed:",
+            versions.len()
+        ))
+        .size(14),
+        Space::new().height(8),
+        version_list,
+        Space::new().height(24),
+        row![
+            button(text("Cancel").size(13))
+                .on_press(Message::CancelBulkOperation)
+                .style(styles::secondary_button)
+                .padding([10, 20]),
+            Space::new().width(Length::Fill),
+            button(text("Install All").size(13))
+                .on_press(Message::ConfirmInstallFromProjects)
+                .style(styles::primary_button)
+                .padding([10, 20]),
+        ]
+        .spacing(16),
+    ]
+    .spacing(4)
+    .width(Length::Fill)
+    .into()
+}
+
+fn confirm_migrate_from_nvm_view<'a>(
+    versions: &'a [String],
+    default_version: Option<&'a str>,
+    set_default: bool,
+) -> Element<'a, Message> {
+    if versions.is_empty() && default_version.is_none() {
+        return column![
+            text("Migrate from nvm").size(20),
+            Space::new().height(12),
+            text("Everything installed under nvm is already available here.").size(14),
+            Space::new().height(24),
+            row![
+                Space::new().width(Length::Fill),
+                button(text("Close").size(13))
+                    .on_press(Message::CloseModal)
+                    .style(styles::secondary_button)
+                    .padding([10, 20]),
+            ],
+        ]
+        .spacing(4)
+        .width(Length::Fill)
+        .into();
+    }
+
     let mut version_list = column![].spacing(4);
 
     for version in versions.iter().take(10) {
@@ -125,6 +789,83 @@ fn confirm_bulk_uninstall_eol_view(versions: &[String]) -> Element<'_, Message>
         );
     }
 
+    let mut content = column![
+        text("Migrate from nvm?").size(20),
+        Space::new().height(12),
+        text(format!(
+            "This will install {} version(s) found under nvm:",
+            versions.len()
+        ))
+        .size(14),
+        Space::new().height(8),
+        version_list,
+    ]
+    .spacing(4)
+    .width(Length::Fill);
+
+    if let Some(default) = default_version {
+        content = content.push(Space::new().height(12));
+        content = content.push(
+            row![
+                toggler(set_default)
+                    .on_toggle(Message::MigrateFromNvmSetDefaultToggled)
+                    .size(18),
+                text(format!("Also set {} as the default", default)).size(12),
+            ]
+            .spacing(8)
+            .align_y(Alignment::Center),
+        );
+    }
+
+    content = content.push(Space::new().height(24));
+    content = content.push(
+        row![
+            button(text("Cancel").size(13))
+                .on_press(Message::CancelBulkOperation)
+                .style(styles::secondary_button)
+                .padding([10, 20]),
+            Space::new().width(Length::Fill),
+            button(text("Migrate").size(13))
+                .on_press(Message::ConfirmMigrateFromNvm)
+                .style(styles::primary_button)
+                .padding([10, 20]),
+        ]
+        .spacing(16),
+    );
+
+    content.into()
+}
+
+fn confirm_bulk_uninstall_eol_view<'a>(
+    versions: &'a [String],
+    kept: &'a [(String, String)],
+) -> Element<'a, Message> {
+    let mut version_list = column![].spacing(4);
+
+    for version in versions.iter().take(10) {
+        version_list = version_list.push(
+            text(format!("Node {}", version))
+                .size(12)
+                .color(iced::Color::from_rgb8(142, 142, 147)),
+        );
+    }
+
+    if versions.len() > 10 {
+        version_list = version_list.push(
+            text(format!("...and {} more", versions.len() - 10))
+                .size(11)
+                .color(iced::Color::from_rgb8(142, 142, 147)),
+        );
+    }
+
+    for (major, dir) in kept {
+        version_list = version_list.push(
+            text(format!("Kept {} (required by {})", major, dir))
+                .size(12)
+                .color(iced::Color::from_rgb8(255, 149, 0)),
+        );
+    }
+
     column![
         text("Remove All EOL Versions?").size(20),
         Space::new().height(12),
@@ -1,9 +1,9 @@
-use iced::widget::{Space, button, container, text_input, tooltip};
-use iced::{Element, Length};
+use iced::widget::{Space, button, container, row, text, text_input, tooltip};
+use iced::{Alignment, Element, Length};
 
 use crate::icon;
 use crate::message::Message;
-use crate::state::MainState;
+use crate::state::{MainState, NetworkStatus};
 use crate::theme::styles;
 use crate::widgets::helpers::styled_tooltip;
 
@@ -33,7 +33,7 @@ pub(super) fn search_bar_view<'a>(state: &'a MainState) -> Element<'a, Message>
         )
     };
 
-    iced::widget::stack![
+    let search_stack = iced::widget::stack![
         input,
         container(clear_btn)
             .align_x(iced::alignment::Horizontal::Right)
@@ -41,6 +41,51 @@ pub(super) fn search_bar_view<'a>(state: &'a MainState) -> Element<'a, Message>
             .width(Length::Fill)
             .height(Length::Fill)
             .padding(iced::Padding::new(0.0).right(4.0)),
+    ];
+
+    let browse_btn = styled_tooltip(
+        button(text("Browse").size(13))
+            .on_press(Message::OpenBrowseVersions)
+            .style(styles::secondary_button)
+            .padding([10, 14]),
+        "Browse all available versions",
+        tooltip::Position::Bottom,
+    );
+
+    row![search_stack, browse_btn, network_indicator(state)]
+        .spacing(10)
+        .align_y(Alignment::Center)
+        .into()
+}
+
+fn network_indicator<'a>(state: &'a MainState) -> Element<'a, Message> {
+    let (dot_style, label): (fn(&iced::Theme) -> container::Style, &str) =
+        match state.available_versions.network_status() {
+            NetworkStatus::Online => (styles::status_dot_online, "Online"),
+            NetworkStatus::Fetching => (styles::status_dot_fetching, "Fetching..."),
+            NetworkStatus::Stale => (styles::status_dot_stale, "Cached"),
+            NetworkStatus::Offline => (styles::status_dot_offline, "Offline"),
+        };
+
+    let indicator = row![
+        container(Space::new().width(8).height(8)).style(dot_style),
+        text(label)
+            .size(11)
+            .color(iced::Color::from_rgb8(142, 142, 147)),
     ]
-    .into()
+    .spacing(6)
+    .align_y(Alignment::Center);
+
+    match state.available_versions.network_status() {
+        NetworkStatus::Stale | NetworkStatus::Offline => styled_tooltip(
+            indicator,
+            state
+                .available_versions
+                .error
+                .as_deref()
+                .unwrap_or("Could not reach the network"),
+            tooltip::Position::Left,
+        ),
+        _ => indicator.into(),
+    }
 }
@@ -1,9 +1,13 @@
 use iced::Element;
-use iced::widget::{button, row, text};
+use iced::widget::{button, row, text, text_input, tooltip};
+use iced::{Alignment, Length};
+
+use versi_platform::EnvironmentId;
 
 use crate::message::Message;
 use crate::state::MainState;
 use crate::theme::styles;
+use crate::widgets::helpers::styled_tooltip;
 
 pub(super) fn environment_tabs_view<'a>(state: &'a MainState) -> Option<Element<'a, Message>> {
     if state.environments.len() <= 1 {
@@ -17,6 +21,18 @@ pub(super) fn environment_tabs_view<'a>(state: &'a MainState) -> Option<Element<
         .map(|(idx, env)| {
             let is_active = idx == state.active_environment_idx;
 
+            if let Some((renaming_idx, draft)) = &state.renaming_environment
+                && *renaming_idx == idx
+            {
+                return text_input(&env.id.display_name(), draft)
+                    .on_input(Message::EnvironmentRenameChanged)
+                    .on_submit(Message::EnvironmentRenameSubmitted)
+                    .size(13)
+                    .padding([8, 16])
+                    .width(Length::Fixed(160.0))
+                    .into();
+            }
+
             if !env.available {
                 let label = if let Some(reason) = &env.error {
                     format!("{} ({})", env.name, reason)
@@ -35,7 +51,28 @@ pub(super) fn environment_tabs_view<'a>(state: &'a MainState) -> Option<Element<
                 styles::inactive_tab_button
             };
 
-            button(text(&env.name).size(13))
+            // Only WSL tabs get a rename affordance; the native tab keeps
+            // its platform name.
+            let can_rename = is_active && matches!(env.id, EnvironmentId::Wsl { .. });
+
+            let mut label = row![text(&env.name).size(13)]
+                .spacing(6)
+                .align_y(Alignment::Center);
+            if env.loading {
+                label = label.push(crate::icon::refresh_spinning(11.0, state.refresh_rotation));
+            }
+            if can_rename {
+                label = label.push(styled_tooltip(
+                    button(text("\u{270E}").size(11))
+                        .on_press(Message::EnvironmentRenameStarted(idx))
+                        .style(styles::ghost_button)
+                        .padding([0, 2]),
+                    "Rename tab",
+                    tooltip::Position::Bottom,
+                ));
+            }
+
+            button(label)
                 .on_press(Message::EnvironmentSelected(idx))
                 .style(style)
                 .padding([8, 16])
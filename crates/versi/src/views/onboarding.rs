@@ -7,10 +7,11 @@ use crate::theme::styles;
 
 pub fn view<'a>(state: &'a OnboardingState, backend_name: &'a str) -> Element<'a, Message> {
     let content = match state.step {
-        OnboardingStep::Welcome => welcome_step(backend_name),
+        OnboardingStep::Welcome => welcome_step(state, backend_name),
         OnboardingStep::SelectBackend => select_backend_step(state),
         OnboardingStep::InstallBackend => install_backend_step(state, backend_name),
         OnboardingStep::ConfigureShell => configure_shell_step(state, backend_name),
+        OnboardingStep::InstallNode => install_node_step(state),
     };
 
     let progress = step_indicator(state);
@@ -44,6 +45,7 @@ fn step_indicator<'a>(state: &'a OnboardingState) -> Element<'a, Message> {
 
     steps.push(("Install", OnboardingStep::InstallBackend));
     steps.push(("Configure Shell", OnboardingStep::ConfigureShell));
+    steps.push(("Install Node", OnboardingStep::InstallNode));
 
     let indicators: Vec<Element<Message>> = steps
         .iter()
@@ -101,10 +103,33 @@ fn full_step_index(step: &OnboardingStep, has_select: bool) -> usize {
                 2
             }
         }
+        OnboardingStep::InstallNode => {
+            if has_select {
+                4
+            } else {
+                3
+            }
+        }
     }
 }
 
-fn welcome_step(backend_name: &str) -> Element<'_, Message> {
+fn welcome_step<'a>(state: &'a OnboardingState, backend_name: &'a str) -> Element<'a, Message> {
+    if let Some(vanished) = state.vanished_backend {
+        return column![
+            text(format!("{} is no longer available", vanished)).size(32),
+            Space::new().height(16),
+            text(format!(
+                "Versi can't find {} anymore \u{2014} it may have been uninstalled while the app was running.",
+                vanished
+            ))
+            .size(16),
+            Space::new().height(8),
+            text("We'll help you reinstall it to get going again.").size(16),
+        ]
+        .spacing(8)
+        .into();
+    }
+
     column![
         text("Welcome to Versi").size(32),
         Space::new().height(16),
@@ -225,37 +250,203 @@ fn configure_shell_step<'a>(
     ]
     .spacing(8);
 
-    for shell in &state.detected_shells {
-        let shell_row = row![
-            text(&shell.shell_name).size(16).width(Length::Fixed(120.0)),
-            if shell.configured {
+    if state.detected_shells.is_empty() {
+        content = content.push(
+            text("No shells detected. You can point Versi at your shell's config file later in Settings under \"Shell Setup\".")
+                .size(14)
+                .color(iced::Color::from_rgb8(142, 142, 147)),
+        );
+        return content.into();
+    }
+
+    // Lead with the login shell when we know it: one big button, everything
+    // else behind an expander. Without a recognized login shell, fall back
+    // to the flat per-shell list.
+    let login_idx = state.login_shell.as_ref().and_then(|login| {
+        state
+            .detected_shells
+            .iter()
+            .position(|s| &s.shell_type == login)
+    });
+
+    let Some(login_idx) = login_idx else {
+        for shell in &state.detected_shells {
+            content = content.push(shell_config_row(shell));
+            content = content.push(Space::new().height(8));
+        }
+        return content.into();
+    };
+
+    let login = &state.detected_shells[login_idx];
+
+    if login.configured {
+        content = content.push(
+            row![
+                text(format!("{} is configured", login.shell_name)).size(16),
                 container(text("Configured").size(14))
                     .padding([4, 8])
-                    .style(crate::theme::styles::badge_lts)
-            } else if shell.configuring {
-                container(text("Configuring...").size(14))
-            } else if let Some(error) = &shell.error {
-                container(text(format!("Error: {}", error)).size(14))
-            } else if shell.config_path.is_none() {
-                container(
-                    text("No config file")
-                        .size(14)
-                        .color(iced::Color::from_rgb8(142, 142, 147)),
-                )
-            } else {
-                container(
-                    button(text("Configure").size(14))
-                        .on_press(Message::OnboardingConfigureShell(shell.shell_type.clone()))
-                        .style(styles::secondary_button)
-                        .padding([6, 12]),
-                )
-            },
-        ]
-        .spacing(16)
-        .align_y(Alignment::Center);
+                    .style(crate::theme::styles::badge_lts),
+            ]
+            .spacing(16)
+            .align_y(Alignment::Center),
+        );
+    } else if login.configuring {
+        content = content.push(text(format!("Configuring {}...", login.shell_name)).size(16));
+    } else if let Some(error) = &login.error {
+        content = content.push(
+            column![
+                text(format!("Configuring {} failed:", login.shell_name)).size(16),
+                text(error).size(14),
+                Space::new().height(16),
+                button(text("Retry"))
+                    .on_press(Message::OnboardingConfigureShell(login.shell_type.clone()))
+                    .style(styles::primary_button),
+            ]
+            .spacing(8),
+        );
+    } else if login.config_path.is_none() {
+        content = content.push(
+            text(format!("{} has no config file to edit", login.shell_name))
+                .size(14)
+                .color(iced::Color::from_rgb8(142, 142, 147)),
+        );
+    } else {
+        content = content.push(
+            button(text(format!("Configure {}", login.shell_name)).size(16))
+                .on_press(Message::OnboardingConfigureShell(login.shell_type.clone()))
+                .style(styles::primary_button)
+                .padding([12, 24])
+                .width(Length::Fill),
+        );
+    }
+    content = content.push(
+        text(format!("{} is your login shell", login.shell_name))
+            .size(12)
+            .color(iced::Color::from_rgb8(142, 142, 147)),
+    );
+
+    if state.detected_shells.len() > 1 {
+        let chevron = if state.show_all_shells {
+            crate::icon::chevron_down(10.0)
+        } else {
+            crate::icon::chevron_right(10.0)
+        };
+        content = content.push(Space::new().height(16));
+        content = content.push(
+            button(
+                row![
+                    chevron,
+                    text(format!(
+                        "Other shells ({})",
+                        state.detected_shells.len() - 1
+                    ))
+                    .size(13),
+                ]
+                .spacing(8)
+                .align_y(Alignment::Center),
+            )
+            .on_press(Message::OnboardingToggleAllShells)
+            .style(styles::ghost_button)
+            .padding([6, 0]),
+        );
+        if state.show_all_shells {
+            for (idx, shell) in state.detected_shells.iter().enumerate() {
+                if idx == login_idx {
+                    continue;
+                }
+                content = content.push(shell_config_row(shell));
+                content = content.push(Space::new().height(8));
+            }
+        }
+    }
+
+    content.into()
+}
+
+fn shell_config_row<'a>(shell: &'a crate::state::ShellConfigStatus) -> Element<'a, Message> {
+    row![
+        text(&shell.shell_name).size(16).width(Length::Fixed(120.0)),
+        if shell.configured {
+            container(text("Configured").size(14))
+                .padding([4, 8])
+                .style(crate::theme::styles::badge_lts)
+        } else if shell.configuring {
+            container(text("Configuring...").size(14))
+        } else if let Some(error) = &shell.error {
+            container(text(format!("Error: {}", error)).size(14))
+        } else if shell.config_path.is_none() {
+            container(
+                text("No config file")
+                    .size(14)
+                    .color(iced::Color::from_rgb8(142, 142, 147)),
+            )
+        } else {
+            container(
+                button(text("Configure").size(14))
+                    .on_press(Message::OnboardingConfigureShell(shell.shell_type.clone()))
+                    .style(styles::secondary_button)
+                    .padding([6, 12]),
+            )
+        },
+    ]
+    .spacing(16)
+    .align_y(Alignment::Center)
+    .into()
+}
+
+fn install_node_step<'a>(state: &'a OnboardingState) -> Element<'a, Message> {
+    let mut content = column![
+        text("Install Node.js").size(28),
+        Space::new().height(16),
+        text("Install the latest LTS release and make it your default in one step.").size(16),
+        Space::new().height(24),
+    ]
+    .spacing(8);
 
-        content = content.push(shell_row);
+    if state.node_installing {
+        let label = match &state.node_install_progress {
+            Some(progress) => {
+                let phase = match progress.phase {
+                    versi_backend::InstallPhase::Starting => "Preparing",
+                    versi_backend::InstallPhase::Downloading => "Downloading",
+                    versi_backend::InstallPhase::Extracting => "Extracting",
+                    versi_backend::InstallPhase::Installing => "Installing",
+                    versi_backend::InstallPhase::Complete => "Finishing up",
+                    versi_backend::InstallPhase::Failed => "Failed",
+                };
+                match progress.percent {
+                    Some(percent) => format!("{}... {:.0}%", phase, percent),
+                    None => format!("{}...", phase),
+                }
+            }
+            None => "Preparing...".to_string(),
+        };
+        content = content.push(text(label).size(16));
+    } else if let Some(error) = &state.node_install_error {
+        content = content.push(
+            column![
+                text("Installation failed:").size(16),
+                text(error).size(14),
+                Space::new().height(16),
+                button(text("Retry"))
+                    .on_press(Message::OnboardingInstallNode)
+                    .style(styles::primary_button),
+            ]
+            .spacing(8),
+        );
+    } else {
+        content = content.push(
+            button(text("Install Latest LTS & Set Default").size(16))
+                .on_press(Message::OnboardingInstallNode)
+                .style(styles::primary_button)
+                .padding([12, 24]),
+        );
         content = content.push(Space::new().height(8));
+        content = content.push(
+            text("Or press Finish to skip \u{2014} you can install versions any time from the main window.")
+                .size(12)
+                .color(iced::Color::from_rgb8(142, 142, 147)),
+        );
     }
 
     content.into()
@@ -274,18 +465,23 @@ fn navigation_buttons<'a>(state: &'a OnboardingState) -> Element<'a, Message> {
     };
 
     let next_label = match state.step {
-        OnboardingStep::ConfigureShell => "Finish",
+        OnboardingStep::InstallNode => "Finish",
         _ => "Next",
     };
 
     let can_proceed = match state.step {
         OnboardingStep::SelectBackend => state.selected_backend.is_some(),
         OnboardingStep::InstallBackend => !state.backend_installing,
-        OnboardingStep::ConfigureShell => state.detected_shells.iter().any(|s| s.configured),
+        OnboardingStep::ConfigureShell => {
+            // With no detected shells there is nothing to configure here;
+            // manual setup lives in Settings, so don't block finishing.
+            state.detected_shells.is_empty() || state.detected_shells.iter().any(|s| s.configured)
+        }
+        OnboardingStep::InstallNode => !state.node_installing,
         _ => true,
     };
 
-    let next_message = if state.step == OnboardingStep::ConfigureShell {
+    let next_message = if state.step == OnboardingStep::InstallNode {
         Message::OnboardingComplete
     } else {
         Message::OnboardingNext
@@ -1,12 +1,18 @@
-use iced::widget::{Space, button, column, container, row, scrollable, text, toggler, tooltip};
+use iced::widget::{
+    Space, button, column, container, row, scrollable, text, text_input, toggler, tooltip,
+};
 use iced::{Alignment, Element, Length};
 
 use crate::icon;
 use crate::message::Message;
-use crate::settings::{AppSettings, ThemeSetting, TrayBehavior};
-use crate::state::{MainState, SettingsModalState, ShellVerificationStatus};
+use crate::settings::{
+    AppSettings, ChangelogSource, CloseAction, DockerImageVariant, FetchOnStartup, GroupSort,
+    ProgressVerbosity, RefreshOnShow, RowDoubleClickAction, ThemeSetting, TrayBehavior,
+};
+use crate::state::{MainState, SettingsModalState, ShellVerificationStatus, UpdateCheckStatus};
 use crate::theme::{is_system_dark, styles};
 use crate::widgets::helpers::nav_icons;
+use versi_shell::ShellType;
 
 pub fn view<'a>(
     settings_state: &'a SettingsModalState,
@@ -60,6 +66,7 @@ pub fn view<'a>(
                 .padding([10, 16]),
         ]
         .spacing(8),
+        accent_row(settings),
         Space::new().height(28),
         text("Preferred Engine").size(14),
         Space::new().height(8),
@@ -67,9 +74,13 @@ pub fn view<'a>(
         text(format!("Currently using: {}", state.backend_name))
             .size(11)
             .color(iced::Color::from_rgb8(142, 142, 147)),
+        offline_mirror_status(state),
         text("Each environment uses whichever engine is available")
             .size(11)
             .color(iced::Color::from_rgb8(142, 142, 147)),
+        Space::new().height(8),
+        reveal_backend_dir_button(state),
+        migrate_from_nvm_section(state),
         Space::new().height(28),
         text("System Tray").size(14),
         Space::new().height(8),
@@ -109,7 +120,116 @@ pub fn view<'a>(
         ]
         .spacing(8)
         .align_y(Alignment::Center),
-        text("\"Always\" keeps the app running in the tray when closed")
+        row![
+            toggler(settings.always_on_top)
+                .on_toggle(Message::AlwaysOnTopToggled)
+                .size(18),
+            text("Always on top").size(12),
+        ]
+        .spacing(8)
+        .align_y(Alignment::Center),
+        text("Keeps the window above others, handy when following a tutorial")
+            .size(11)
+            .color(iced::Color::from_rgb8(142, 142, 147)),
+        Space::new().height(12),
+        text("Refresh on window open").size(12),
+        row![
+            button(text("Always").size(13))
+                .on_press(Message::RefreshOnShowChanged(RefreshOnShow::Always))
+                .style(if settings.refresh_on_show == RefreshOnShow::Always {
+                    styles::primary_button
+                } else {
+                    styles::secondary_button
+                })
+                .padding([10, 16]),
+            button(text("If Empty").size(13))
+                .on_press(Message::RefreshOnShowChanged(RefreshOnShow::IfEmpty))
+                .style(if settings.refresh_on_show == RefreshOnShow::IfEmpty {
+                    styles::primary_button
+                } else {
+                    styles::secondary_button
+                })
+                .padding([10, 16]),
+            button(text("Never").size(13))
+                .on_press(Message::RefreshOnShowChanged(RefreshOnShow::Never))
+                .style(if settings.refresh_on_show == RefreshOnShow::Never {
+                    styles::primary_button
+                } else {
+                    styles::secondary_button
+                })
+                .padding([10, 16]),
+        ]
+        .spacing(8),
+        text("Whether reopening the window from the tray reloads the version list")
+            .size(11)
+            .color(iced::Color::from_rgb8(142, 142, 147)),
+        Space::new().height(12),
+        text("Fetch versions on startup").size(12),
+        row![
+            button(text("Always").size(13))
+                .on_press(Message::FetchOnStartupChanged(FetchOnStartup::Always))
+                .style(if settings.fetch_on_startup == FetchOnStartup::Always {
+                    styles::primary_button
+                } else {
+                    styles::secondary_button
+                })
+                .padding([10, 16]),
+            button(text("Only when window shown").size(13))
+                .on_press(Message::FetchOnStartupChanged(FetchOnStartup::WhenShown))
+                .style(if settings.fetch_on_startup == FetchOnStartup::WhenShown {
+                    styles::primary_button
+                } else {
+                    styles::secondary_button
+                })
+                .padding([10, 16]),
+        ]
+        .spacing(8),
+        text("Only when window shown skips the remote fetch while starting minimized to the tray")
+            .size(11)
+            .color(iced::Color::from_rgb8(142, 142, 147)),
+        Space::new().height(16),
+        text("When Closing the Window").size(14),
+        Space::new().height(8),
+        row![
+            button(text("Quit").size(13))
+                .on_press(Message::CloseActionChanged(CloseAction::Quit))
+                .style(if settings.close_action == CloseAction::Quit {
+                    styles::primary_button
+                } else {
+                    styles::secondary_button
+                })
+                .padding([10, 16]),
+            button(text("Minimize to Tray").size(13))
+                .on_press(Message::CloseActionChanged(CloseAction::MinimizeToTray))
+                .style(if settings.close_action == CloseAction::MinimizeToTray {
+                    styles::primary_button
+                } else {
+                    styles::secondary_button
+                })
+                .padding([10, 16]),
+            button(text("Ask").size(13))
+                .on_press(Message::CloseActionChanged(CloseAction::Ask))
+                .style(if settings.close_action == CloseAction::Ask {
+                    styles::primary_button
+                } else {
+                    styles::secondary_button
+                })
+                .padding([10, 16]),
+        ]
+        .spacing(8),
+        text("Minimizing to tray needs a tray icon; without one the app quits")
+            .size(11)
+            .color(iced::Color::from_rgb8(142, 142, 147)),
+        Space::new().height(12),
+        row![
+            toggler(settings.confirm_quit_during_operations)
+                .on_toggle(Message::ConfirmQuitDuringOperationsToggled)
+                .size(18),
+            text("Confirm quit while operations are running").size(12),
+        ]
+        .spacing(8)
+        .align_y(Alignment::Center),
+        text("Quitting mid-install kills the operation and can leave partial installs behind")
             .size(11)
             .color(iced::Color::from_rgb8(142, 142, 147)),
         Space::new().height(28),
@@ -168,11 +288,24 @@ pub fn view<'a>(
                 .color(iced::Color::from_rgb8(142, 142, 147)),
         );
     } else {
-        content = content.push(
-            text("Options for new shell configurations")
+        content = content.push(match settings_state.shell_flags_updated {
+            Some(0) => {
+                text("No shells configured yet \u{2014} applies to future shell configurations")
+                    .size(11)
+                    .color(iced::Color::from_rgb8(142, 142, 147))
+            }
+            Some(count) => text(format!(
+                "Updated {} flags in {} {}",
+                state.backend_name,
+                count,
+                if count == 1 { "shell" } else { "shells" }
+            ))
+            .size(11)
+            .color(iced::Color::from_rgb8(52, 199, 89)),
+            None => text("Options for new shell configurations")
                 .size(11)
                 .color(iced::Color::from_rgb8(142, 142, 147)),
-        );
+        });
     }
 
     content = content.push(Space::new().height(28));
@@ -183,6 +316,57 @@ pub fn view<'a>(
         content = content.push(text("Checking shell configuration...").size(12));
     } else if settings_state.shell_statuses.is_empty() {
         content = content.push(text("No shells detected").size(12));
+        content = content.push(
+            text("Point Versi at your shell's config file to set it up manually")
+                .size(11)
+                .color(iced::Color::from_rgb8(142, 142, 147)),
+        );
+        content = content.push(Space::new().height(8));
+        content = content.push(
+            row![
+                manual_shell_button("Bash", ShellType::Bash, settings_state),
+                manual_shell_button("Zsh", ShellType::Zsh, settings_state),
+                manual_shell_button("Fish", ShellType::Fish, settings_state),
+                manual_shell_button("Nushell", ShellType::Nushell, settings_state),
+                manual_shell_button("PowerShell", ShellType::PowerShell, settings_state),
+            ]
+            .spacing(8),
+        );
+        content = content.push(Space::new().height(8));
+        let configure_button = if settings_state.manual_shell_path.trim().is_empty() {
+            button(text("Configure").size(13))
+                .style(styles::secondary_button)
+                .padding([8, 16])
+        } else {
+            button(text("Configure").size(13))
+                .on_press(Message::ManualShellConfigure)
+                .style(styles::secondary_button)
+                .padding([8, 16])
+        };
+        content = content.push(
+            row![
+                text_input("~/.bashrc", &settings_state.manual_shell_path)
+                    .on_input(Message::ManualShellPathChanged)
+                    .on_submit(Message::ManualShellConfigure)
+                    .size(13)
+                    .padding([8, 12]),
+                configure_button,
+            ]
+            .spacing(8)
+            .align_y(Alignment::Center),
+        );
+        content = content.push(
+            text("The path is remembered and used for that shell from then on")
+                .size(11)
+                .color(iced::Color::from_rgb8(142, 142, 147)),
+        );
+        if let Some(Err(error)) = &settings_state.manual_shell_result {
+            content = content.push(
+                text(error)
+                    .size(11)
+                    .color(iced::Color::from_rgb8(255, 69, 58)),
+            );
+        }
     } else {
         for shell in &settings_state.shell_statuses {
             let is_configured_check = matches!(shell.status, ShellVerificationStatus::Configured);
@@ -252,6 +436,404 @@ pub fn view<'a>(
         }
     }
 
+    if let Some(hint) = &settings_state.restart_hint {
+        content = content.push(Space::new().height(8));
+        content = content.push(
+            text(hint)
+                .size(11)
+                .color(iced::Color::from_rgb8(255, 149, 0)),
+        );
+    }
+
+    content = content.push(Space::new().height(28));
+    content = content.push(text("Behavior").size(14));
+    content = content.push(Space::new().height(8));
+    content = content.push(
+        row![
+            double_click_button("Set Default", RowDoubleClickAction::SetDefault, settings),
+            double_click_button("Use", RowDoubleClickAction::Use, settings),
+            double_click_button("Changelog", RowDoubleClickAction::Changelog, settings),
+        ]
+        .spacing(8),
+    );
+    content = content.push(
+        text("Action performed when double-clicking a version row")
+            .size(11)
+            .color(iced::Color::from_rgb8(142, 142, 147)),
+    );
+    content = content.push(Space::new().height(12));
+    content = content.push(
+        row![
+            changelog_source_button("nodejs.org Blog", ChangelogSource::NodejsBlog, settings),
+            changelog_source_button("GitHub Releases", ChangelogSource::GithubReleases, settings),
+            changelog_source_button("Changelog File", ChangelogSource::ChangelogFile, settings),
+        ]
+        .spacing(8),
+    );
+    content = content.push(
+        text("Where the Changelog button opens release notes")
+            .size(11)
+            .color(iced::Color::from_rgb8(142, 142, 147)),
+    );
+    content = content.push(Space::new().height(12));
+    content = content.push(
+        row![
+            group_sort_button("Newest First", GroupSort::NewestFirst, settings),
+            group_sort_button("LTS First", GroupSort::LtsFirst, settings),
+        ]
+        .spacing(8),
+    );
+    content = content.push(
+        text("LTS First keeps active LTS majors on top and folds end-of-life majors at the bottom")
+            .size(11)
+            .color(iced::Color::from_rgb8(142, 142, 147)),
+    );
+    content = content.push(Space::new().height(12));
+    content = content.push(
+        row![
+            progress_verbosity_button("Minimal", ProgressVerbosity::Minimal, settings),
+            progress_verbosity_button("Normal", ProgressVerbosity::Normal, settings),
+            progress_verbosity_button("Detailed", ProgressVerbosity::Detailed, settings),
+        ]
+        .spacing(8),
+    );
+    content = content.push(
+        text("How much the install banner shows: just the version, phase and percent, or speed and time remaining too")
+            .size(11)
+            .color(iced::Color::from_rgb8(142, 142, 147)),
+    );
+    content = content.push(Space::new().height(12));
+    content = content.push(
+        row![
+            toggler(settings.show_all_patches)
+                .on_toggle(Message::ShowAllPatchesToggled)
+                .size(18),
+            text("Show all patch releases").size(12),
+        ]
+        .spacing(8)
+        .align_y(Alignment::Center),
+    );
+    content = content.push(
+        text("Search results otherwise show only the newest patch of each minor")
+            .size(11)
+            .color(iced::Color::from_rgb8(142, 142, 147)),
+    );
+    content = content.push(Space::new().height(12));
+    content = content.push(
+        row![
+            toggler(settings.group_by_minor)
+                .on_toggle(Message::GroupByMinorToggled)
+                .size(18),
+            text("Group installed versions by minor").size(12),
+        ]
+        .spacing(8)
+        .align_y(Alignment::Center),
+    );
+    content = content.push(
+        text("Expanded majors show collapsible minor subgroups instead of a flat patch list")
+            .size(11)
+            .color(iced::Color::from_rgb8(142, 142, 147)),
+    );
+    content = content.push(Space::new().height(12));
+    content = content.push(
+        row![
+            toggler(settings.group_by_codename)
+                .on_toggle(Message::GroupByCodenameToggled)
+                .size(18),
+            text("Group installed versions by LTS codename").size(12),
+        ]
+        .spacing(8)
+        .align_y(Alignment::Center),
+    );
+    content = content.push(
+        text("LTS majors are titled by codename (Iron, Hydrogen) and non-LTS lines combine under Current")
+            .size(11)
+            .color(iced::Color::from_rgb8(142, 142, 147)),
+    );
+    content = content.push(Space::new().height(12));
+    content = content.push(
+        row![
+            toggler(settings.auto_expand_default_group)
+                .on_toggle(Message::AutoExpandDefaultGroupToggled)
+                .size(18),
+            text("Keep the default version's group expanded").size(12),
+        ]
+        .spacing(8)
+        .align_y(Alignment::Center),
+    );
+    content = content.push(
+        text("The group holding the current default stays open after refreshes, even when end-of-life groups fold")
+            .size(11)
+            .color(iced::Color::from_rgb8(142, 142, 147)),
+    );
+    content = content.push(Space::new().height(12));
+    content = content.push(
+        row![
+            toggler(settings.remember_search)
+                .on_toggle(Message::RememberSearchToggled)
+                .size(18),
+            text("Remember search between sessions").size(12),
+        ]
+        .spacing(8)
+        .align_y(Alignment::Center),
+    );
+    content = content.push(
+        text("Restores the search box from the previous session on launch")
+            .size(11)
+            .color(iced::Color::from_rgb8(142, 142, 147)),
+    );
+    content = content.push(Space::new().height(12));
+    content = content.push(
+        row![
+            toggler(settings.skip_uninstall_confirm)
+                .on_toggle(Message::SkipUninstallConfirmToggled)
+                .size(18),
+            text("Don't ask before uninstalling").size(12),
+        ]
+        .spacing(8)
+        .align_y(Alignment::Center),
+    );
+    content = content.push(
+        text("Single versions uninstall immediately; bulk operations still confirm")
+            .size(11)
+            .color(iced::Color::from_rgb8(142, 142, 147)),
+    );
+    content = content.push(Space::new().height(12));
+    if !settings.ignored_eol_majors.is_empty() {
+        let mut chips = row![].spacing(8).align_y(Alignment::Center);
+        for major in &settings.ignored_eol_majors {
+            chips = chips.push(
+                row![
+                    text(format!("Node {}.x", major)).size(12),
+                    button(text("Remove").size(11))
+                        .on_press(Message::IgnoredEolMajorRemoved(*major))
+                        .style(styles::row_action_button_danger)
+                        .padding([4, 8]),
+                ]
+                .spacing(4)
+                .align_y(Alignment::Center),
+            );
+        }
+        content = content.push(chips);
+        content = content.push(Space::new().height(8));
+    }
+    content = content.push(
+        row![
+            text_input("Major (e.g. 16)", &settings_state.eol_pin_input)
+                .on_input(Message::IgnoredEolMajorInputChanged)
+                .on_submit(Message::IgnoredEolMajorAdded)
+                .size(13)
+                .padding([8, 12])
+                .width(Length::Fixed(140.0)),
+            button(text("Never Treat as EOL").size(13))
+                .on_press(Message::IgnoredEolMajorAdded)
+                .style(styles::secondary_button)
+                .padding([8, 16]),
+        ]
+        .spacing(8)
+        .align_y(Alignment::Center),
+    );
+    content = content.push(
+        text("Listed majors keep no End-of-Life badge and are skipped by Clean Up")
+            .size(11)
+            .color(iced::Color::from_rgb8(142, 142, 147)),
+    );
+
+    content = content.push(Space::new().height(28));
+    content = content.push(text("Docker").size(14));
+    content = content.push(Space::new().height(8));
+    content = content.push(
+        row![
+            docker_variant_button("node", DockerImageVariant::Default, settings),
+            docker_variant_button("node-alpine", DockerImageVariant::Alpine, settings),
+            docker_variant_button("node-slim", DockerImageVariant::Slim, settings),
+        ]
+        .spacing(8),
+    );
+    content = content.push(
+        text("Base image used when copying a Dockerfile FROM line")
+            .size(11)
+            .color(iced::Color::from_rgb8(142, 142, 147)),
+    );
+
+    content = content.push(Space::new().height(28));
+    content = content.push(text("Project Directories").size(14));
+    content = content.push(Space::new().height(8));
+    for (index, dir) in settings.project_dirs.iter().enumerate() {
+        content = content.push(
+            row![
+                text(dir.display().to_string()).size(12),
+                Space::new().width(Length::Fill),
+                button(text("Remove").size(11))
+                    .on_press(Message::ProjectDirRemoved(index))
+                    .style(styles::row_action_button_danger)
+                    .padding([4, 8]),
+            ]
+            .spacing(8)
+            .align_y(Alignment::Center),
+        );
+    }
+    content = content.push(
+        row![
+            text_input("/path/to/projects", &settings_state.project_dir_input)
+                .on_input(Message::ProjectDirInputChanged)
+                .on_submit(Message::ProjectDirAdded)
+                .size(13)
+                .padding([8, 12]),
+            button(text("Add").size(13))
+                .on_press(Message::ProjectDirAdded)
+                .style(styles::secondary_button)
+                .padding([8, 16]),
+        ]
+        .spacing(8)
+        .align_y(Alignment::Center),
+    );
+    content = content.push(
+        text("Scanned (one level deep) for .nvmrc and .node-version files")
+            .size(11)
+            .color(iced::Color::from_rgb8(142, 142, 147)),
+    );
+    content = content.push(Space::new().height(8));
+    if settings.project_dirs.is_empty() {
+        content = content.push(
+            button(text("Install Pinned Versions").size(13))
+                .style(styles::secondary_button)
+                .padding([10, 16]),
+        );
+    } else {
+        content = content.push(
+            button(text("Install Pinned Versions").size(13))
+                .on_press(Message::ScanProjects)
+                .style(styles::secondary_button)
+                .padding([10, 16]),
+        );
+    }
+
+    #[cfg(windows)]
+    {
+        content = content.push(Space::new().height(28));
+        content = content.push(text("Manual WSL Environments").size(14));
+        content = content.push(Space::new().height(8));
+        for (index, env) in settings.manual_wsl_environments.iter().enumerate() {
+            content = content.push(
+                row![
+                    text(format!("{} \u{2014} {}", env.distro, env.backend_path)).size(12),
+                    Space::new().width(Length::Fill),
+                    button(text("Remove").size(11))
+                        .on_press(Message::ManualWslEnvironmentRemoved(index))
+                        .style(styles::row_action_button_danger)
+                        .padding([4, 8]),
+                ]
+                .spacing(8)
+                .align_y(Alignment::Center),
+            );
+        }
+        content = content.push(
+            row![
+                text_input("Distro name", &settings_state.manual_wsl_distro_input)
+                    .on_input(Message::ManualWslDistroInputChanged)
+                    .on_submit(Message::ManualWslEnvironmentAdded)
+                    .size(13)
+                    .padding([8, 12]),
+                text_input("/path/to/fnm", &settings_state.manual_wsl_path_input)
+                    .on_input(Message::ManualWslPathInputChanged)
+                    .on_submit(Message::ManualWslEnvironmentAdded)
+                    .size(13)
+                    .padding([8, 12]),
+                button(text("Add").size(13))
+                    .on_press(Message::ManualWslEnvironmentAdded)
+                    .style(styles::secondary_button)
+                    .padding([8, 16]),
+            ]
+            .spacing(8)
+            .align_y(Alignment::Center),
+        );
+        content = content.push(
+            text("For distros where the backend isn't auto-detected; applied on the next launch")
+                .size(11)
+                .color(iced::Color::from_rgb8(142, 142, 147)),
+        );
+    }
+
+    content = content.push(Space::new().height(28));
+    content = content.push(text("HTTP Proxy").size(14));
+    content = content.push(Space::new().height(8));
+    content = content.push(
+        text_input("proxy.example.com:3128", &settings.proxy.host)
+            .on_input(Message::ProxyHostChanged)
+            .size(13)
+            .padding([8, 12]),
+    );
+    content = content.push(Space::new().height(8));
+    content = content.push(
+        row![
+            text_input("Username (optional)", &settings.proxy.username)
+                .on_input(Message::ProxyUsernameChanged)
+                .size(13)
+                .padding([8, 12]),
+            text_input("Password", &settings.proxy.password)
+                .on_input(Message::ProxyPasswordChanged)
+                .secure(true)
+                .size(13)
+                .padding([8, 12]),
+        ]
+        .spacing(8),
+    );
+    content = content.push(
+        text("Used as HTTPS_PROXY when downloading Node versions. Leave blank to use your system proxy settings.")
+            .size(11)
+            .color(iced::Color::from_rgb8(142, 142, 147)),
+    );
+
+    content = content.push(Space::new().height(28));
+    content = content.push(text("Install Timeout").size(14));
+    content = content.push(Space::new().height(8));
+    content = content.push(
+        row![
+            install_timeout_button("2 min", 2, settings.install_timeout_mins),
+            install_timeout_button("5 min", 5, settings.install_timeout_mins),
+            install_timeout_button("15 min", 15, settings.install_timeout_mins),
+            install_timeout_button("Off", 0, settings.install_timeout_mins),
+        ]
+        .spacing(8),
+    );
+    content = content.push(
+        text("Stalled installs are cancelled after this long so queued operations can continue")
+            .size(11)
+            .color(iced::Color::from_rgb8(142, 142, 147)),
+    );
+
+    content = content.push(Space::new().height(28));
+    content = content.push(text("Updates").size(14));
+    content = content.push(Space::new().height(8));
+    content = content.push(update_check_row(
+        "Versi".to_string(),
+        &settings_state.app_update_check,
+        Message::CheckForAppUpdate,
+    ));
+    content = content.push(Space::new().height(8));
+    content = content.push(update_check_row(
+        state.backend_name.to_string(),
+        &settings_state.backend_update_check,
+        Message::CheckForBackendUpdate,
+    ));
+    content = content.push(Space::new().height(12));
+    content = content.push(
+        row![
+            toggler(settings.self_update)
+                .on_toggle(Message::SelfUpdateToggled)
+                .size(18),
+            text("Update Versi in place").size(12),
+        ]
+        .spacing(8)
+        .align_y(Alignment::Center),
+    );
+    content = content.push(
+        text("Downloads the new release and swaps it in; unsupported installs open the release page instead")
+            .size(11)
+            .color(iced::Color::from_rgb8(142, 142, 147)),
+    );
+
     content = content.push(Space::new().height(28));
     content = content.push(text("Advanced").size(14));
     content = content.push(Space::new().height(8));
@@ -269,13 +851,20 @@ pub fn view<'a>(
         let paths = versi_platform::AppPaths::new();
         paths.log_file().to_string_lossy().to_string()
     };
-    let log_size_text = match settings_state.log_file_size {
+    let mut log_size_text = match settings_state.log_file_size {
         Some(0) => "empty".to_string(),
         Some(size) if size < 1024 => format!("{} B", size),
         Some(size) if size < 1024 * 1024 => format!("{:.1} KB", size as f64 / 1024.0),
         Some(size) => format!("{:.1} MB", size as f64 / (1024.0 * 1024.0)),
         None => "not found".to_string(),
     };
+    if let Some(backup) = settings_state.log_backup_size {
+        log_size_text = format!(
+            "{} + {:.1} MB rotated",
+            log_size_text,
+            backup as f64 / (1024.0 * 1024.0)
+        );
+    }
     content = content.push(
         row![
             text("Log file: ")
@@ -294,6 +883,10 @@ pub fn view<'a>(
     content = content.push(Space::new().height(8));
     content = content.push(
         row![
+            button(text("View Log").size(11))
+                .on_press(Message::ViewLogFile)
+                .style(styles::secondary_button)
+                .padding([4, 10]),
             button(text("Show in Folder").size(11))
                 .on_press(Message::RevealLogFile)
                 .style(styles::secondary_button)
@@ -302,9 +895,87 @@ pub fn view<'a>(
                 .on_press(Message::ClearLogFile)
                 .style(styles::secondary_button)
                 .padding([4, 10]),
+            button(text("Copy Bug Report").size(11))
+                .on_press(Message::CopyBugReport)
+                .style(styles::secondary_button)
+                .padding([4, 10]),
         ]
         .spacing(8),
     );
+    content = content.push(
+        text("Copies system details, environments, and the last 50 log lines for pasting into a bug report.")
+            .size(11)
+            .color(iced::Color::from_rgb8(142, 142, 147)),
+    );
+    content = content.push(Space::new().height(12));
+    content = content.push(
+        row![
+            text_input(
+                "FNM_DIR override (e.g. /data/fnm)",
+                &settings_state.fnm_dir_input
+            )
+            .on_input(Message::FnmDirInputChanged)
+            .on_submit(Message::FnmDirSubmitted)
+            .size(13)
+            .padding([8, 12])
+            .width(Length::Fixed(320.0)),
+            button(text("Apply").size(13))
+                .on_press(Message::FnmDirSubmitted)
+                .style(styles::secondary_button)
+                .padding([8, 16]),
+        ]
+        .spacing(8)
+        .align_y(Alignment::Center),
+    );
+    if let Some(error) = &settings_state.fnm_dir_error {
+        let mut error_row = row![
+            text(error.as_str())
+                .size(11)
+                .color(iced::Color::from_rgb8(255, 69, 58))
+        ]
+        .spacing(8)
+        .align_y(Alignment::Center);
+        // The missing-layout case is fixable in place; path typos are not.
+        if error.contains("missing") {
+            error_row = error_row.push(
+                button(text("Create Structure").size(11))
+                    .on_press(Message::CreateFnmDirStructure)
+                    .style(styles::secondary_button)
+                    .padding([4, 10]),
+            );
+        }
+        content = content.push(error_row);
+    } else {
+        content = content.push(
+            text("Custom fnm data directory (FNM_DIR); leave empty for the detected one. Applies on restart")
+                .size(11)
+                .color(iced::Color::from_rgb8(142, 142, 147)),
+        );
+    }
+    if let Some(size) = settings_state.cache_size {
+        let cache_size_text = match size {
+            0 => "empty".to_string(),
+            size if size < 1024 * 1024 => format!("{:.1} KB", size as f64 / 1024.0),
+            size => format!("{:.1} MB", size as f64 / (1024.0 * 1024.0)),
+        };
+        let mut clear_cache = button(text("Clear Cache").size(11))
+            .style(styles::secondary_button)
+            .padding([4, 10]);
+        if size > 0 {
+            clear_cache = clear_cache.on_press(Message::RequestClearCache);
+        }
+        content = content.push(Space::new().height(12));
+        content = content.push(
+            row![
+                text(format!("Download cache: {}", cache_size_text))
+                    .size(11)
+                    .color(iced::Color::from_rgb8(142, 142, 147)),
+                clear_cache,
+            ]
+            .spacing(8)
+            .align_y(Alignment::Center),
+        );
+    }
     column![
         container(header).padding(iced::Padding::new(0.0).right(24.0)),
         Space::new().height(12),
@@ -317,6 +988,215 @@ pub fn view<'a>(
     .into()
 }
 
+fn update_check_row<'a>(
+    label: String,
+    status: &'a UpdateCheckStatus,
+    on_check: Message,
+) -> Element<'a, Message> {
+    let check_button = if *status == UpdateCheckStatus::Checking {
+        button(text("Checking...").size(11))
+            .style(styles::secondary_button)
+            .padding([4, 10])
+    } else {
+        button(text("Check Now").size(11))
+            .on_press(on_check)
+            .style(styles::secondary_button)
+            .padding([4, 10])
+    };
+
+    let mut check_row = row![
+        text(label).size(12).width(Length::Fixed(140.0)),
+        check_button,
+    ]
+    .spacing(8)
+    .align_y(Alignment::Center);
+
+    let status_text = match status {
+        UpdateCheckStatus::Idle | UpdateCheckStatus::Checking => None,
+        UpdateCheckStatus::UpToDate => {
+            Some(("You're up to date", iced::Color::from_rgb8(52, 199, 89)))
+        }
+        UpdateCheckStatus::UpdateAvailable => {
+            Some(("Update available", iced::Color::from_rgb8(255, 149, 0)))
+        }
+        UpdateCheckStatus::Failed => Some(("Check failed", iced::Color::from_rgb8(255, 69, 58))),
+    };
+
+    if let Some((label, color)) = status_text {
+        check_row = check_row.push(text(label).size(11).color(color));
+    }
+
+    check_row.into()
+}
+
+fn group_sort_button<'a>(
+    label: &'static str,
+    sort: GroupSort,
+    settings: &'a AppSettings,
+) -> Element<'a, Message> {
+    let is_selected = settings.group_sort == sort;
+    button(text(label).size(13))
+        .on_press(Message::GroupSortChanged(sort))
+        .style(if is_selected {
+            styles::primary_button
+        } else {
+            styles::secondary_button
+        })
+        .padding([10, 16])
+        .into()
+}
+
+fn progress_verbosity_button<'a>(
+    label: &'static str,
+    verbosity: ProgressVerbosity,
+    settings: &'a AppSettings,
+) -> Element<'a, Message> {
+    let is_selected = settings.progress_verbosity == verbosity;
+    button(text(label).size(13))
+        .on_press(Message::ProgressVerbosityChanged(verbosity))
+        .style(if is_selected {
+            styles::primary_button
+        } else {
+            styles::secondary_button
+        })
+        .padding([10, 16])
+        .into()
+}
+
+fn changelog_source_button<'a>(
+    label: &'static str,
+    source: ChangelogSource,
+    settings: &'a AppSettings,
+) -> Element<'a, Message> {
+    let is_selected = settings.changelog_source == source;
+    button(text(label).size(13))
+        .on_press(Message::ChangelogSourceChanged(source))
+        .style(if is_selected {
+            styles::primary_button
+        } else {
+            styles::secondary_button
+        })
+        .padding([10, 16])
+        .into()
+}
+
+fn double_click_button<'a>(
+    label: &'static str,
+    action: RowDoubleClickAction,
+    settings: &'a AppSettings,
+) -> Element<'a, Message> {
+    let is_selected = settings.row_double_click_action == action;
+    button(text(label).size(13))
+        .on_press(Message::RowDoubleClickActionChanged(action))
+        .style(if is_selected {
+            styles::primary_button
+        } else {
+            styles::secondary_button
+        })
+        .padding([10, 16])
+        .into()
+}
+
+fn manual_shell_button<'a>(
+    label: &'static str,
+    shell_type: ShellType,
+    settings_state: &'a SettingsModalState,
+) -> Element<'a, Message> {
+    let is_selected = settings_state.manual_shell_type == shell_type;
+    button(text(label).size(11))
+        .on_press(Message::ManualShellTypeSelected(shell_type))
+        .style(if is_selected {
+            styles::primary_button
+        } else {
+            styles::secondary_button
+        })
+        .padding([4, 10])
+        .into()
+}
+
+fn docker_variant_button<'a>(
+    label: &'static str,
+    variant: DockerImageVariant,
+    settings: &'a AppSettings,
+) -> Element<'a, Message> {
+    let is_selected = settings.docker_image_variant == variant;
+    button(text(label).size(13))
+        .on_press(Message::DockerImageVariantChanged(variant))
+        .style(if is_selected {
+            styles::primary_button
+        } else {
+            styles::secondary_button
+        })
+        .padding([10, 16])
+        .into()
+}
+
+fn install_timeout_button<'a>(label: &'a str, mins: u64, current: u64) -> Element<'a, Message> {
+    button(text(label).size(13))
+        .on_press(Message::InstallTimeoutChanged(mins))
+        .style(if current == mins {
+            styles::primary_button
+        } else {
+            styles::secondary_button
+        })
+        .padding([10, 16])
+        .into()
+}
+
+fn reveal_backend_dir_button<'a>(state: &'a MainState) -> Element<'a, Message> {
+    let label = format!("Open {} Directory", state.backend_name);
+    let btn = button(text(label).size(11))
+        .style(styles::secondary_button)
+        .padding([4, 10]);
+
+    if state.backend.backend_info().data_dir.is_some() {
+        btn.on_press(Message::RevealBackendDir).into()
+    } else {
+        tooltip(
+            btn,
+            container(text("Data directory not detected").size(12))
+                .padding([4, 8])
+                .style(styles::tooltip_container),
+            tooltip::Position::Bottom,
+        )
+        .gap(4.0)
+        .into()
+    }
+}
+
+/// Inline status for air-gapped setups: shown when the backend's Node
+/// distribution mirror points at a local directory instead of a remote URL.
+fn offline_mirror_status<'a>(state: &'a MainState) -> Element<'a, Message> {
+    match state.backend.backend_info().offline_mirror() {
+        Some(path) => text(format!("Offline mirror: {}", path))
+            .size(11)
+            .color(iced::Color::from_rgb8(142, 142, 147))
+            .into(),
+        None => column![].into(),
+    }
+}
+
+/// Offers to bring nvm-installed versions over to fnm. Only shown while
+/// fnm is the active engine and nvm was detected alongside it.
+fn migrate_from_nvm_section<'a>(state: &'a MainState) -> Element<'a, Message> {
+    if state.backend_name != "fnm" || !state.detected_backends.contains(&"nvm") {
+        return column![].into();
+    }
+
+    column![
+        Space::new().height(8),
+        button(text("Migrate from nvm").size(11))
+            .on_press(Message::MigrateFromNvmRequested)
+            .style(styles::secondary_button)
+            .padding([4, 10]),
+        text("Installs the versions nvm has that fnm doesn't, after confirmation")
+            .size(11)
+            .color(iced::Color::from_rgb8(142, 142, 147)),
+    ]
+    .spacing(4)
+    .into()
+}
+
 fn engine_button<'a>(
     name: &'static str,
     is_selected: bool,
@@ -346,6 +1226,30 @@ fn engine_button<'a>(
     }
 }
 
+/// The system-accent toggle, shown only on platforms with an OS accent color.
+fn accent_row(settings: &AppSettings) -> Element<'_, Message> {
+    #[cfg(any(target_os = "macos", windows))]
+    {
+        column![
+            Space::new().height(8),
+            row![
+                toggler(settings.use_system_accent)
+                    .on_toggle(Message::UseSystemAccentToggled)
+                    .size(18),
+                text("Use system accent color").size(12),
+            ]
+            .spacing(8)
+            .align_y(Alignment::Center),
+        ]
+        .into()
+    }
+    #[cfg(not(any(target_os = "macos", windows)))]
+    {
+        let _ = settings;
+        Space::new().into()
+    }
+}
+
 fn engine_selector<'a>(settings: &'a AppSettings, state: &'a MainState) -> Element<'a, Message> {
     let preferred = settings.preferred_backend.as_deref().unwrap_or("fnm");
     let fnm_detected = state.detected_backends.contains(&"fnm");
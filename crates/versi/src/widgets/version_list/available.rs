@@ -11,7 +11,7 @@ use crate::message::Message;
 use crate::state::OperationQueue;
 use crate::theme::styles;
 
-pub(super) fn available_version_row<'a>(
+pub(crate) fn available_version_row<'a>(
     version: &'a RemoteVersion,
     schedule: Option<&ReleaseSchedule>,
     operation_queue: &'a OperationQueue,
@@ -25,7 +25,9 @@ pub(super) fn compute_latest_by_major(
 pub(super) fn filter_available_versions<'a>(
     versions: &'a [RemoteVersion],
     query: &str,
+    show_all_patches: bool,
 ) -> Vec<&'a RemoteVersion> {
+    let query = super::normalize_query(query);
     let query_lower = query.to_lowercase();
 
     let mut filtered: Vec<&RemoteVersion> = versions
@@ -47,6 +49,12 @@ pub(super) fn filter_available_versions<'a>(
 
     filtered.sort_by(|a, b| b.version.cmp(&a.version));
 
+    // The collapsed default keeps the list scannable; "show all patches"
+    // renders every matching release instead.
+    if show_all_patches {
+        return filtered;
+    }
+
     let mut latest_by_minor: HashMap<(u32, u32), &RemoteVersion> = HashMap::new();
     for v in &filtered {
         let key = (v.version.major, v.version.minor);
@@ -15,18 +15,36 @@ use super::item::version_item_view;
 pub(super) fn version_group_view<'a>(
     group: &'a VersionGroup,
     default: &'a Option<versi_backend::NodeVersion>,
+    pinned_major: Option<u32>,
+    multishell: &'a Option<versi_backend::NodeVersion>,
     search_query: &'a str,
-    update_available: Option<String>,
+    update_available: Option<(String, String)>,
     schedule: Option<&ReleaseSchedule>,
     operation_queue: &'a OperationQueue,
     hovered_version: &'a Option<String>,
+    last_used: &'a std::collections::HashMap<String, std::time::SystemTime>,
+    install_sources: &'a std::collections::HashMap<String, crate::settings::InstallSource>,
+    bundled_npm: &'a std::collections::HashMap<String, Option<String>>,
+    ignored_eol_majors: &'a [u32],
+    group_by_minor: bool,
+    expanded_minors: &'a std::collections::HashSet<(u32, u32)>,
+    supports_exec: bool,
+    supports_reveal: bool,
 ) -> Element<'a, Message> {
     let has_lts = group.versions.iter().any(|v| v.lts_codename.is_some());
     let has_default = group
         .versions
         .iter()
         .any(|v| default.as_ref().map(|d| d == &v.version).unwrap_or(false));
-    let is_eol = schedule.map(|s| !s.is_active(group.major)).unwrap_or(false);
+    let is_eol = schedule.map(|s| !s.is_active(group.major)).unwrap_or(false)
+        && !ignored_eol_majors.contains(&group.major);
+
+    // The merged "Current" group spans several majors, so the per-major bulk
+    // actions and minor subgroups don't apply to it.
+    let single_major = group
+        .versions
+        .iter()
+        .all(|v| v.version.major == group.major);
 
     let chevron = if group.is_expanded {
         icon::chevron_down(12.0)
@@ -34,17 +52,32 @@ pub(super) fn version_group_view<'a>(
         icon::chevron_right(12.0)
     };
 
+    let title = match &group.label {
+        Some(label) => label.clone(),
+        None => format!("Node {}.x", group.major),
+    };
+
     let mut header_row = row![
         chevron,
-        text(format!("Node {}.x", group.major)).size(16),
+        text(title).size(16),
         text(format!("({} installed)", group.versions.len())).size(12),
     ]
     .spacing(8)
     .align_y(Alignment::Center);
 
     if has_lts {
+        // Name the LTS line once up here instead of on every row; when the
+        // group title is already the codename, the plain badge suffices.
+        let label = match group
+            .versions
+            .iter()
+            .find_map(|v| v.lts_codename.as_deref())
+        {
+            Some(codename) if group.label.is_none() => format!("LTS: {}", codename),
+            _ => "LTS".to_string(),
+        };
         header_row = header_row.push(
-            container(text("LTS").size(10))
+            container(text(label).size(10))
                 .padding([2, 6])
                 .style(styles::badge_lts),
         );
@@ -77,17 +110,52 @@ pub(super) fn version_group_view<'a>(
 
     let mut header_actions = row![].spacing(8).align_y(Alignment::Center);
 
-    if let Some(new_version) = update_available {
+    if let Some((installed_latest, new_version)) = update_available {
         let version_to_install = new_version.clone();
         header_actions = header_actions.push(
-            button(container(text(format!("{} available", new_version)).size(10)).padding([2, 6]))
-                .on_press(Message::StartInstall(version_to_install))
-                .style(styles::update_badge_button)
-                .padding([0, 4]),
+            button(
+                container(
+                    text(format!(
+                        "{} \u{2192} {} available",
+                        installed_latest, new_version
+                    ))
+                    .size(10),
+                )
+                .padding([2, 6]),
+            )
+            .on_press(Message::StartInstall(version_to_install))
+            .style(styles::update_badge_button)
+            .padding([0, 4]),
         );
     }
 
-    if group.is_expanded && group.versions.len() > 1 {
+    if group.is_expanded && single_major {
+        // Floating default pin: the default follows the newest installed
+        // release of this major instead of one exact version.
+        let is_pinned = pinned_major == Some(group.major);
+        let pin_style = if is_pinned {
+            styles::ghost_button_active
+                as fn(&iced::Theme, iced::widget::button::Status) -> iced::widget::button::Style
+        } else {
+            styles::ghost_button
+        };
+        let (label, msg) = if is_pinned {
+            ("Unpin Default", Message::UnpinDefaultMajor)
+        } else {
+            (
+                "Pin Default",
+                Message::PinDefaultMajor { major: group.major },
+            )
+        };
+        header_actions = header_actions.push(
+            button(text(label).size(10))
+                .on_press(msg)
+                .style(pin_style)
+                .padding([4, 8]),
+        );
+    }
+
+    if group.is_expanded && group.versions.len() > 1 && single_major {
         header_actions = header_actions.push(
             button(text("Keep Latest").size(10))
                 .on_press(Message::RequestBulkUninstallMajorExceptLatest { major: group.major })
@@ -117,10 +185,42 @@ pub(super) fn version_group_view<'a>(
             .filter(|v| filter_version(v, search_query))
             .collect();
 
-        let items: Vec<Element<Message>> = filtered_versions
-            .iter()
-            .map(|v| version_item_view(v, default, operation_queue, hovered_version))
-            .collect();
+        let items: Vec<Element<Message>> = if group_by_minor && single_major {
+            minor_subgroups(
+                group.major,
+                &filtered_versions,
+                default,
+                multishell,
+                operation_queue,
+                hovered_version,
+                last_used,
+                install_sources,
+                bundled_npm,
+                expanded_minors,
+                supports_exec,
+                supports_reveal,
+                has_lts,
+            )
+        } else {
+            filtered_versions
+                .iter()
+                .map(|v| {
+                    version_item_view(
+                        v,
+                        default,
+                        multishell,
+                        operation_queue,
+                        hovered_version,
+                        last_used,
+                        install_sources,
+                        bundled_npm,
+                        supports_exec,
+                        supports_reveal,
+                        has_lts,
+                    )
+                })
+                .collect()
+        };
 
         container(
             column![
@@ -145,3 +245,97 @@ pub(super) fn version_group_view<'a>(
             .into()
     }
 }
+
+/// Buckets an expanded major's versions into collapsible `major.minor.x`
+/// subgroups. The incoming list is already sorted, so minors appear in
+/// first-seen (descending) order.
+fn minor_subgroups<'a>(
+    major: u32,
+    versions: &[&'a InstalledVersion],
+    default: &'a Option<versi_backend::NodeVersion>,
+    multishell: &'a Option<versi_backend::NodeVersion>,
+    operation_queue: &'a OperationQueue,
+    hovered_version: &'a Option<String>,
+    last_used: &'a std::collections::HashMap<String, std::time::SystemTime>,
+    install_sources: &'a std::collections::HashMap<String, crate::settings::InstallSource>,
+    bundled_npm: &'a std::collections::HashMap<String, Option<String>>,
+    expanded_minors: &'a std::collections::HashSet<(u32, u32)>,
+    supports_exec: bool,
+    supports_reveal: bool,
+    hide_lts_badge: bool,
+) -> Vec<Element<'a, Message>> {
+    let mut minors: Vec<u32> = Vec::new();
+    for v in versions {
+        if !minors.contains(&v.version.minor) {
+            minors.push(v.version.minor);
+        }
+    }
+
+    let mut items: Vec<Element<Message>> = Vec::new();
+    for minor in minors {
+        let in_minor: Vec<&InstalledVersion> = versions
+            .iter()
+            .copied()
+            .filter(|v| v.version.minor == minor)
+            .collect();
+        let is_expanded = expanded_minors.contains(&(major, minor));
+
+        let chevron = if is_expanded {
+            icon::chevron_down(10.0)
+        } else {
+            icon::chevron_right(10.0)
+        };
+
+        items.push(
+            button(
+                row![
+                    chevron,
+                    text(format!("{}.{}.x", major, minor)).size(13),
+                    text(format!("({})", in_minor.len())).size(11),
+                ]
+                .spacing(8)
+                .align_y(Alignment::Center),
+            )
+            .on_press(Message::MinorGroupToggled { major, minor })
+            .style(|theme, status| {
+                let mut style = iced::widget::button::text(theme, status);
+                style.text_color = theme.palette().text;
+                style
+            })
+            .padding([6, 12])
+            .into(),
+        );
+
+        if is_expanded {
+            let rows: Vec<Element<Message>> = in_minor
+                .iter()
+                .map(|v| {
+                    version_item_view(
+                        v,
+                        default,
+                        multishell,
+                        operation_queue,
+                        hovered_version,
+                        last_used,
+                        install_sources,
+                        bundled_npm,
+                        supports_exec,
+                        supports_reveal,
+                        hide_lts_badge,
+                    )
+                })
+                .collect();
+            items.push(
+                container(column(rows).spacing(2))
+                    .padding(iced::Padding {
+                        top: 0.0,
+                        right: 0.0,
+                        bottom: 0.0,
+                        left: 24.0,
+                    })
+                    .into(),
+            );
+        }
+    }
+    items
+}
@@ -11,19 +11,34 @@ use crate::theme::styles;
 pub(super) fn version_item_view<'a>(
     version: &'a InstalledVersion,
     default: &'a Option<versi_backend::NodeVersion>,
+    multishell: &'a Option<versi_backend::NodeVersion>,
     operation_queue: &'a OperationQueue,
     hovered_version: &'a Option<String>,
+    last_used: &'a std::collections::HashMap<String, std::time::SystemTime>,
+    install_sources: &'a std::collections::HashMap<String, crate::settings::InstallSource>,
+    bundled_npm: &'a std::collections::HashMap<String, Option<String>>,
+    supports_exec: bool,
+    supports_reveal: bool,
+    hide_lts_badge: bool,
 ) -> Element<'a, Message> {
     let is_default = default
         .as_ref()
         .map(|d| d == &version.version)
         .unwrap_or(false);
+    let is_in_use = multishell
+        .as_ref()
+        .map(|m| m == &version.version)
+        .unwrap_or(false);
 
     let version_str = version.version.to_string();
     let version_display = version_str.clone();
     let version_for_default = version_str.clone();
     let version_for_changelog = version_str.clone();
+    let version_for_docker = version_str.clone();
+    let version_for_run = version_str.clone();
+    let version_for_reveal = version_str.clone();
     let version_for_hover = version_str.clone();
+    let version_for_double_click = version_str.clone();
 
     let active_op = operation_queue.active_operation_for(&version_str);
     let is_pending = operation_queue.has_pending_for_version(&version_str);
@@ -39,7 +54,9 @@ pub(super) fn version_item_view<'a>(
         .spacing(8)
         .align_y(Alignment::Center);
 
-    if let Some(lts) = &version.lts_codename {
+    // The group header already names the LTS line; repeating "LTS: Iron" on
+    // every patch row underneath it is noise.
+    if !hide_lts_badge && let Some(lts) = &version.lts_codename {
         row_content = row_content.push(
             container(text(format!("LTS: {}", lts)).size(11))
                 .padding([2, 6])
@@ -47,6 +64,39 @@ pub(super) fn version_item_view<'a>(
         );
     }
 
+    if let Some(arch) = &version.arch {
+        row_content = row_content.push(
+            container(text(arch).size(11))
+                .padding([2, 6])
+                .style(styles::badge_arch),
+        );
+    }
+
+    if install_sources.contains_key(&version_str) {
+        row_content = row_content.push(
+            container(text("unofficial").size(11))
+                .padding([2, 6])
+                .style(styles::badge_unofficial),
+        );
+    }
+
+    // Probed lazily on first hover; nothing shows until the result lands.
+    if let Some(Some(npm)) = bundled_npm.get(&version_str) {
+        row_content = row_content.push(
+            text(format!("npm {}", npm))
+                .size(11)
+                .color(iced::Color::from_rgb8(142, 142, 147)),
+        );
+    }
+
+    if is_in_use {
+        row_content = row_content.push(
+            container(text("in use").size(11))
+                .padding([2, 6])
+                .style(styles::badge_in_use),
+        );
+    }
+
     if is_default {
         row_content = row_content.push(
             container(text("default").size(11))
@@ -57,6 +107,16 @@ pub(super) fn version_item_view<'a>(
 
     row_content = row_content.push(Space::new().width(Length::Fill));
 
+    let last_used_label = match last_used.get(&version_str) {
+        Some(at) => format!("last used {}", format_relative_time(at)),
+        None => "never used".to_string(),
+    };
+    row_content = row_content.push(
+        text(last_used_label)
+            .size(11)
+            .color(iced::Color::from_rgb8(142, 142, 147)),
+    );
+
     if let Some(size) = version.disk_size {
         row_content = row_content.push(text(format_bytes(size)).size(12));
     }
@@ -91,6 +151,55 @@ pub(super) fn version_item_view<'a>(
         );
     }
 
+    if show_actions {
+        row_content = row_content.push(
+            button(text("Copy FROM").size(11))
+                .on_press(Message::CopyDockerfileLine(version_for_docker))
+                .style(action_style)
+                .padding([4, 8]),
+        );
+    } else {
+        row_content = row_content.push(
+            button(text("Copy FROM").size(11))
+                .style(action_style)
+                .padding([4, 8]),
+        );
+    }
+
+    if supports_reveal {
+        if show_actions {
+            row_content = row_content.push(
+                button(text("Show Files").size(11))
+                    .on_press(Message::RevealVersionDir(version_for_reveal))
+                    .style(action_style)
+                    .padding([4, 8]),
+            );
+        } else {
+            row_content = row_content.push(
+                button(text("Show Files").size(11))
+                    .style(action_style)
+                    .padding([4, 8]),
+            );
+        }
+    }
+
+    if supports_exec {
+        if show_actions {
+            row_content = row_content.push(
+                button(text("Run...").size(11))
+                    .on_press(Message::OpenRunCommand(version_for_run))
+                    .style(action_style)
+                    .padding([4, 8]),
+            );
+        } else {
+            row_content = row_content.push(
+                button(text("Run...").size(11))
+                    .style(action_style)
+                    .padding([4, 8]),
+            );
+        }
+    }
+
     if is_default {
         row_content = row_content.push(
             button(text("Default").size(12))
@@ -150,9 +259,41 @@ pub(super) fn version_item_view<'a>(
     mouse_area(row_container)
         .on_enter(Message::VersionRowHovered(Some(version_for_hover)))
         .on_exit(Message::VersionRowHovered(None))
+        .on_double_click(Message::VersionRowDoubleClicked(version_for_double_click))
         .into()
 }
 
+fn format_relative_time(at: &std::time::SystemTime) -> String {
+    const MINUTE: u64 = 60;
+    const HOUR: u64 = 60 * MINUTE;
+    const DAY: u64 = 24 * HOUR;
+    const MONTH: u64 = 30 * DAY;
+    const YEAR: u64 = 365 * DAY;
+
+    // A timestamp in the future (clock change) reads as "just now".
+    let secs = at.elapsed().map(|d| d.as_secs()).unwrap_or(0);
+
+    let (amount, unit) = if secs >= YEAR {
+        (secs / YEAR, "year")
+    } else if secs >= MONTH {
+        (secs / MONTH, "month")
+    } else if secs >= DAY {
+        (secs / DAY, "day")
+    } else if secs >= HOUR {
+        (secs / HOUR, "hour")
+    } else if secs >= MINUTE {
+        (secs / MINUTE, "minute")
+    } else {
+        return "just now".to_string();
+    };
+
+    if amount == 1 {
+        format!("1 {} ago", unit)
+    } else {
+        format!("{} {}s ago", amount, unit)
+    }
+}
+
 pub(super) fn format_bytes(bytes: u64) -> String {
     const KB: u64 = 1024;
     const MB: u64 = KB * 1024;
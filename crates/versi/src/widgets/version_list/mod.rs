@@ -1,4 +1,4 @@
-mod available;
+pub(crate) mod available;
 mod filters;
 mod group;
 mod item;
@@ -12,12 +12,31 @@ use versi_backend::{InstalledVersion, RemoteVersion, VersionGroup};
 use versi_core::ReleaseSchedule;
 
 use crate::message::Message;
+use crate::settings::GroupSort;
 use crate::state::{EnvironmentState, OperationQueue};
 use crate::theme::styles;
 
 use filters::{compute_latest_by_major, filter_available_versions};
 
+/// Normalizes a search query for version matching: surrounding whitespace and
+/// a leading "node" or "v" (as typed in "Node 20" or "v20") are dropped so
+/// all three forms match the same versions. Codename searches ("iron") pass
+/// through untouched.
+pub(crate) fn normalize_query(query: &str) -> &str {
+    let query = query.trim();
+    let query = query
+        .strip_prefix("Node")
+        .or_else(|| query.strip_prefix("node"))
+        .map(str::trim_start)
+        .unwrap_or(query);
+    match query.strip_prefix('v') {
+        Some(rest) if rest.starts_with(|c: char| c.is_ascii_digit()) => rest,
+        _ => query,
+    }
+}
+
 fn filter_group(group: &VersionGroup, query: &str) -> bool {
+    let query = normalize_query(query);
     if query.is_empty() {
         return true;
     }
@@ -39,6 +58,7 @@ fn filter_group(group: &VersionGroup, query: &str) -> bool {
 }
 
 fn filter_version(version: &InstalledVersion, query: &str) -> bool {
+    let query = normalize_query(query);
     if query.is_empty() {
         return true;
     }
@@ -58,13 +78,72 @@ fn filter_version(version: &InstalledVersion, query: &str) -> bool {
             .unwrap_or(false)
 }
 
+fn first_install_view<'a>(
+    remote_versions: &'a [RemoteVersion],
+    operation_queue: &'a OperationQueue,
+) -> Element<'a, Message> {
+    let latest_lts = remote_versions
+        .iter()
+        .filter(|v| v.lts_codename.is_some())
+        .max_by(|a, b| a.version.cmp(&b.version));
+
+    let cta: Element<Message> = match latest_lts {
+        Some(lts) => {
+            let version_str = lts.version.to_string();
+            let is_busy = operation_queue.is_current_version(&version_str)
+                || operation_queue.has_pending_for_version(&version_str);
+            let label = if is_busy {
+                "Installing...".to_string()
+            } else {
+                format!("Install Latest LTS ({})", version_str)
+            };
+            let mut btn = button(text(label).size(16))
+                .style(styles::primary_button)
+                .padding([12, 24]);
+            if !is_busy {
+                btn = btn.on_press(Message::StartInstall(version_str));
+            }
+            btn.into()
+        }
+        None => text("Search above to install your first version.")
+            .size(14)
+            .into(),
+    };
+
+    container(
+        column![
+            text("No Node.js versions installed yet").size(16),
+            text("Get started with the current long-term support release.").size(14),
+            Space::new().height(16),
+            cta,
+        ]
+        .spacing(8)
+        .align_x(Alignment::Center),
+    )
+    .center_x(Length::Fill)
+    .center_y(Length::Fill)
+    .height(Length::Fill)
+    .into()
+}
+
 pub fn view<'a>(
     env: &'a EnvironmentState,
     search_query: &'a str,
     remote_versions: &'a [RemoteVersion],
+    remote_loading: bool,
     schedule: Option<&'a ReleaseSchedule>,
     operation_queue: &'a OperationQueue,
     hovered_version: &'a Option<String>,
+    range_match: Option<&'a RemoteVersion>,
+    pinned_major: Option<u32>,
+    group_sort: &'a GroupSort,
+    last_used: &'a std::collections::HashMap<String, std::time::SystemTime>,
+    install_sources: &'a std::collections::HashMap<String, crate::settings::InstallSource>,
+    show_all_patches: bool,
+    group_by_minor: bool,
+    ignored_eol_majors: &'a [u32],
+    supports_exec: bool,
+    supports_reveal: bool,
 ) -> Element<'a, Message> {
     let latest_by_major = compute_latest_by_major(remote_versions);
 
@@ -100,18 +179,42 @@ pub fn view<'a>(
         .into();
     }
 
+    // fnm is working but nothing is installed yet: offer the latest LTS from
+    // the cached remote list instead of just pointing at the search box.
+    if env.installed_versions.is_empty()
+        && !env.loading
+        && env.error.is_none()
+        && search_query.is_empty()
+    {
+        return first_install_view(remote_versions, operation_queue);
+    }
+
     let installed_set: HashSet<String> = env
         .installed_versions
         .iter()
         .map(|v| v.version.to_string())
         .collect();
 
-    let filtered_groups: Vec<&VersionGroup> = env
+    let mut filtered_groups: Vec<&VersionGroup> = env
         .version_groups
         .iter()
         .filter(|g| filter_group(g, search_query))
         .collect();
 
+    if *group_sort == GroupSort::LtsFirst {
+        // Stable sort keeps the descending major order within each bucket.
+        filtered_groups.sort_by_key(|g| {
+            let is_eol = schedule.map(|s| !s.is_active(g.major)).unwrap_or(false)
+                && !ignored_eol_majors.contains(&g.major);
+            let is_lts = g.versions.iter().any(|v| v.lts_codename.is_some());
+            match (is_eol, is_lts) {
+                (true, _) => 2u8,
+                (false, true) => 0,
+                (false, false) => 1,
+            }
+        });
+    }
+
     let default_version = &env.default_version;
 
     let mut content_items: Vec<Element<Message>> = Vec::new();
@@ -119,10 +222,12 @@ pub fn view<'a>(
     if !filtered_groups.is_empty() && search_query.is_empty() {
         for g in &filtered_groups {
             let installed_latest = g.versions.iter().map(|v| &v.version).max();
+            // Both ends of the delta go to the badge, so it reads
+            // "v20.11.0 → v20.11.1 available" rather than just the target.
             let update_available = latest_by_major.get(&g.major).and_then(|latest| {
                 installed_latest.and_then(|installed| {
                     if latest > installed {
-                        Some(latest.to_string())
+                        Some((installed.to_string(), latest.to_string()))
                     } else {
                         None
                     }
@@ -131,17 +236,72 @@ pub fn view<'a>(
             content_items.push(group::version_group_view(
                 g,
                 default_version,
+                pinned_major,
+                &env.multishell_version,
                 search_query,
                 update_available,
                 schedule,
                 operation_queue,
                 hovered_version,
+                last_used,
+                install_sources,
+                &env.bundled_npm,
+                ignored_eol_majors,
+                group_by_minor,
+                &env.expanded_minors,
+                supports_exec,
+                supports_reveal,
             ));
         }
     }
 
+    if !search_query.is_empty()
+        && let Some(best) = range_match
+    {
+        let version_str = best.version.to_string();
+        let is_busy = operation_queue.is_current_version(&version_str)
+            || operation_queue.has_pending_for_version(&version_str);
+
+        let install_button = if is_busy {
+            button(text("Installing...").size(12))
+                .style(styles::primary_button)
+                .padding([6, 12])
+        } else if installed_set.contains(&version_str) {
+            button(text("Installed").size(12))
+                .style(styles::secondary_button)
+                .padding([6, 12])
+        } else {
+            button(text("Install").size(12))
+                .on_press(Message::StartInstall(version_str.clone()))
+                .style(styles::primary_button)
+                .padding([6, 12])
+        };
+
+        content_items.push(
+            container(
+                iced::widget::row![
+                    text(format!(
+                        "Best match for {}: {}",
+                        search_query.trim(),
+                        version_str
+                    ))
+                    .size(14),
+                    Space::new().width(Length::Fill),
+                    install_button,
+                ]
+                .spacing(8)
+                .align_y(Alignment::Center)
+                .padding([4, 8]),
+            )
+            .style(styles::card_container)
+            .padding(12)
+            .into(),
+        );
+    }
+
     if !search_query.is_empty() {
-        let available_list = filter_available_versions(remote_versions, search_query);
+        let available_list =
+            filter_available_versions(remote_versions, search_query, show_all_patches);
 
         if !available_list.is_empty() {
             let available_rows: Vec<Element<Message>> = available_list
@@ -163,6 +323,30 @@ pub fn view<'a>(
                     .padding(12)
                     .into(),
             );
+        } else if remote_loading {
+            // The environment spinner only covers installed versions; while
+            // the remote list is still being fetched an empty result here
+            // would read as "nothing to install". Cancel aborts a hung fetch
+            // and falls back to the cached list.
+            content_items.push(
+                container(
+                    iced::widget::row![
+                        text("Loading available versions...")
+                            .size(12)
+                            .color(iced::Color::from_rgb8(142, 142, 147)),
+                        Space::new().width(Length::Fill),
+                        button(text("Cancel").size(12))
+                            .on_press(Message::CancelRemoteFetch)
+                            .style(styles::ghost_button)
+                            .padding([4, 8]),
+                    ]
+                    .align_y(Alignment::Center),
+                )
+                .style(styles::card_container)
+                .padding(12)
+                .width(Length::Fill)
+                .into(),
+            );
         }
     }
 
@@ -193,3 +377,51 @@ pub fn view<'a>(
     .height(Length::Fill)
     .into()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn installed(version: &str) -> InstalledVersion {
+        InstalledVersion {
+            version: version.parse().unwrap(),
+            is_default: false,
+            lts_codename: Some("Iron".to_string()),
+            install_date: None,
+            disk_size: None,
+            arch: None,
+        }
+    }
+
+    #[test]
+    fn test_normalize_query_strips_whitespace_and_v() {
+        assert_eq!(normalize_query(" 20 "), "20");
+        assert_eq!(normalize_query("v20"), "20");
+        assert_eq!(normalize_query("v20.11"), "20.11");
+    }
+
+    #[test]
+    fn test_normalize_query_strips_node_prefix() {
+        assert_eq!(normalize_query("Node 20"), "20");
+        assert_eq!(normalize_query("node v20"), "20");
+    }
+
+    #[test]
+    fn test_normalize_query_keeps_codenames() {
+        // A leading "v" only comes off before a digit, so codename and "lts"
+        // searches are untouched.
+        assert_eq!(normalize_query("iron"), "iron");
+        assert_eq!(normalize_query("lts"), "lts");
+    }
+
+    #[test]
+    fn test_filter_version_accepts_all_query_shapes() {
+        let version = installed("v20.11.0");
+        assert!(filter_version(&version, "20"));
+        assert!(filter_version(&version, "v20"));
+        assert!(filter_version(&version, " 20 "));
+        assert!(filter_version(&version, "Node 20"));
+        assert!(filter_version(&version, "iron"));
+        assert!(!filter_version(&version, "v18"));
+    }
+}